@@ -0,0 +1,123 @@
+use std::convert::Infallible;
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+// What a part produces. `Display` gives the form the puzzle page expects, which is also how
+// answers are stored in `aocN/answers` and the submission log.
+
+#[derive(Debug, Clone)]
+pub enum Answer {
+    Int(i128),
+    Text(String),
+    // `x,y`, as asked for by the days that want a position
+    Coord(i64, i64),
+    // The part found nothing to answer with
+    Unsolved,
+}
+
+impl Answer {
+    // The string to post, `None` when there is nothing to submit
+    pub fn submission(&self) -> Option<String> {
+        match self {
+            Answer::Unsolved => None,
+            a => Some(a.to_string()),
+        }
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Int(n) => write!(f, "{}", n),
+            Answer::Text(s) => write!(f, "{}", s),
+            Answer::Coord(x, y) => write!(f, "{},{}", x, y),
+            Answer::Unsolved => write!(f, "-"),
+        }
+    }
+}
+
+// Answers are equal when they would be submitted the same way, so `Text("4,2")` from a
+// program's output matches `Coord(4, 2)` read back from a file
+impl PartialEq for Answer {
+    fn eq(&self, other: &Answer) -> bool {
+        self.submission() == other.submission()
+    }
+}
+
+impl Eq for Answer {}
+
+// Integers and `x,y` pairs get their own variants, anything else is text
+impl FromStr for Answer {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Answer, Infallible> {
+        let s = s.trim();
+        if let Ok(n) = s.parse() {
+            return Ok(Answer::Int(n));
+        }
+        if let Some((x, y)) = s.split_once(',') {
+            if let (Ok(x), Ok(y)) = (x.parse(), y.parse()) {
+                return Ok(Answer::Coord(x, y));
+            }
+        }
+        Ok(if s.is_empty() || s == "-" { Answer::Unsolved } else { Answer::Text(s.to_owned()) })
+    }
+}
+
+macro_rules! from_int {
+    ($($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            fn from(n: $t) -> Answer {
+                Answer::Int(n as i128)
+            }
+        })*
+    };
+}
+
+from_int!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize, i128);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Answer {
+        Answer::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Answer {
+        Answer::Text(s.to_owned())
+    }
+}
+
+impl<T: TryInto<i64>> From<(T, T)> for Answer {
+    fn from((x, y): (T, T)) -> Answer {
+        match (x.try_into(), y.try_into()) {
+            (Ok(x), Ok(y)) => Answer::Coord(x, y),
+            _ => panic!("coordinate out of range"),
+        }
+    }
+}
+
+impl<T: Into<Answer>> From<Option<T>> for Answer {
+    fn from(a: Option<T>) -> Answer {
+        a.map_or(Answer::Unsolved, Into::into)
+    }
+}
+
+#[cfg(test)]
+mod answer {
+    use super::*;
+
+    #[test]
+    fn canonical() {
+        assert_eq!(Answer::from(42u64).to_string(), "42");
+        assert_eq!(Answer::from((6usize, 1usize)).to_string(), "6,1");
+        assert_eq!("6,1".parse::<Answer>().unwrap(), Answer::Coord(6, 1));
+        assert_eq!("-17".parse::<Answer>().unwrap(), Answer::Int(-17));
+        assert_eq!("4,6,3".parse::<Answer>().unwrap(), Answer::from("4,6,3"));
+        assert_eq!(Answer::from("4,2"), Answer::Coord(4, 2));
+        assert_ne!(Answer::from(42u32), Answer::from("042"));
+        assert_eq!(Answer::from(None::<usize>), Answer::Unsolved);
+        assert_eq!(Answer::Unsolved.submission(), None);
+        assert_eq!(Answer::from(Some(7u32)).submission().as_deref(), Some("7"));
+    }
+}
@@ -0,0 +1,133 @@
+use crate::utils::{read_day, day_dirs, InputSource};
+use crate::{Answer, Year};
+use std::fs;
+use std::path::Path;
+
+// Each day keeps its expected answers in `aocN/answers` of its year's `src`, one per line:
+//
+//     <input file> <part> <answer>
+//
+// e.g. `example2 1 772`. Parts without a meaningful answer for an input are left out.
+// Answers are always read from the year crate, even when inputs come from `AOC_INPUT_DIR`.
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Expected {
+    pub file: String,
+    pub part: u32,
+    pub answer: Answer,
+}
+
+pub fn read_answers(year: &Year, day: u32) -> Vec<Expected> {
+    let path = Path::new(year.dir).join(format!("aoc{}/answers", day));
+    let filename = path.display().to_string();
+    let text: Vec<String> = InputSource::File(path).read().unwrap_or_else(|_| panic!("couldn't read {}", filename));
+    text.iter()
+        .filter(|l| !l.trim().is_empty() && !l.starts_with('#'))
+        .map(|l| {
+            let fields: Vec<&str> = l.split_whitespace().collect();
+            if fields.len() != 3 {
+                panic!("{}: expected `<file> <part> <answer>`, got {:?}", filename, l);
+            }
+            Expected {
+                file: fields[0].to_owned(),
+                part: fields[1].parse::<u32>().unwrap_or_else(|_| panic!("{}: invalid part in {:?}", filename, l)),
+                answer: fields[2].parse().unwrap(),
+            }
+        })
+        .collect()
+}
+
+pub fn expected_answer(answers: &[Expected], file: &str, part: u32) -> Option<Answer> {
+    answers.iter()
+        .find(|e| e.file == file && e.part == part)
+        .map(|e| e.answer.clone())
+}
+
+// All `example*` and `input` files of a day across its directories, examples first
+pub fn input_files(year: &Year, day: u32) -> Vec<String> {
+    let mut files: Vec<String> = day_dirs(year, day).iter()
+        .filter_map(|dir| fs::read_dir(dir).ok())
+        .flat_map(|entries| entries
+            .filter_map(|e| e.ok())
+            .filter_map(|e| e.file_name().into_string().ok())
+            .filter(|name| name.starts_with("example") || name == "input"))
+        .collect();
+    files.sort_by_key(|name| (name == "input", name.clone()));
+    files.dedup();
+    files
+}
+
+// Every recorded answer of `year` that its day doesn't reproduce, or whose input file is missing
+pub fn mismatches(year: &Year) -> Vec<String> {
+    let mut mismatches = vec![];
+    for day in year.days {
+        let answers = read_answers(year, day.day);
+        let files = input_files(year, day.day);
+        for e in answers.iter().filter(|e| !files.contains(&e.file)) {
+            mismatches.push(format!("aoc{}/{} part {}: no such input file", day.day, e.file, e.part));
+        }
+        for file in files {
+            let text = read_day(year, day.day, &file).expect("couldn't read input");
+            let solution = match day.parse(text) {
+                Ok(s) => s,
+                Err(e) => {
+                    mismatches.push(format!("aoc{}/{}: {}", day.day, file, e));
+                    continue;
+                }
+            };
+            for part in [1, 2] {
+                let expected = match expected_answer(&answers, &file, part) {
+                    Some(a) => a,
+                    None => continue,
+                };
+                let actual = match part {
+                    1 => solution.part1(),
+                    _ => solution.part2(),
+                };
+                if actual != expected {
+                    mismatches.push(format!("aoc{}/{} part {}: expected {}, got {}", day.day, file, part, expected, actual));
+                }
+            }
+        }
+    }
+    mismatches
+}
+
+#[cfg(test)]
+mod answers {
+    use super::*;
+    use crate::{boxed, AocError, Day, Solution};
+    use std::env;
+
+    // Answers the number of lines of its input in both parts
+    struct Count(u32);
+
+    impl Solution for Count {
+        fn parse(inp: Vec<String>) -> Result<Self, AocError> {
+            Ok(Count(inp.len() as u32))
+        }
+
+        fn part1(&self) -> Answer {
+            Answer::from(self.0)
+        }
+
+        fn part2(&self) -> Answer {
+            Answer::from(self.0)
+        }
+    }
+
+    const DAYS: &[Day] = &[Day { day: 1, parse: boxed::<Count>, generate: |_| vec![] }];
+
+    #[test]
+    fn recorded() {
+        let dir = env::temp_dir().join(format!("aoc-answers-{}", std::process::id()));
+        fs::create_dir_all(dir.join("aoc1")).unwrap();
+        fs::write(dir.join("aoc1/example"), "a\nb\n").unwrap();
+        fs::write(dir.join("aoc1/answers"), "# expected\nexample 1 2\nexample 2 3\ninput 1 5\n").unwrap();
+        let year = Year { year: 2024, dir: Box::leak(dir.to_string_lossy().into_owned().into_boxed_str()), days: DAYS };
+        assert_eq!(input_files(&year, 1), vec!["example"]);
+        assert_eq!(expected_answer(&read_answers(&year, 1), "example", 1), Some(Answer::from(2u32)));
+        assert_eq!(mismatches(&year), vec!["aoc1/input part 1: no such input file", "aoc1/example part 2: expected 3, got 2"]);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
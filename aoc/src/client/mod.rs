@@ -0,0 +1,186 @@
+use crate::utils::input_dir;
+use crate::Year;
+use std::env;
+use std::fs;
+use std::io::{Error, ErrorKind, Write};
+use std::path::PathBuf;
+use std::process::{Command, Stdio};
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+pub mod submit;
+#[cfg(test)]
+mod mock;
+
+// Talks to adventofcode.com through `curl`, authenticated with the session cookie of a logged in browser.
+// Fetched inputs are cached in `$AOC_INPUT_DIR/<year>/aocN/input` (or `aocN/input` of the year's `src`) and
+// never requested again.
+
+pub const SESSION_VAR: &str = "AOC_SESSION";
+pub const BASE_URL: &str = "https://adventofcode.com";
+// Minimum time between two requests, shared by every client using the same cache
+pub const MIN_INTERVAL: Duration = Duration::from_secs(5);
+const USER_AGENT: &str = concat!("aoc/", env!("CARGO_PKG_VERSION"), " (https://github.com/rkjk/aoc2024)");
+// Time of the last request in unix milliseconds, kept in the cache directory
+const STAMP_FILE: &str = ".aoc_last_request";
+
+#[derive(Debug, Clone)]
+pub struct Client {
+    pub base_url: String,
+    session: String,
+    // Directory laid out like a year's `src/`
+    pub cache: PathBuf,
+    // Directory holding the `aocN/answers` and `aocN/submissions` files, normally the year's `src`
+    pub records: PathBuf,
+    pub interval: Duration,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str, cache: PathBuf) -> Client {
+        Client {
+            base_url: base_url.trim_end_matches('/').to_owned(),
+            session: session.trim().to_owned(),
+            records: cache.clone(),
+            cache,
+            interval: MIN_INTERVAL,
+        }
+    }
+
+    // For the puzzles of `year`. Without a session cached inputs can still be looked up, but every
+    // request fails.
+    pub fn from_env(year: &Year) -> Client {
+        let session = env::var(SESSION_VAR).unwrap_or_default();
+        let url = format!("{}/{}", BASE_URL, year.year);
+        Client {
+            records: PathBuf::from(year.dir),
+            ..Client::new(&url, &session, input_dir(year).unwrap_or_else(|| PathBuf::from(year.dir)))
+        }
+    }
+
+    pub fn input_path(&self, day: u32) -> PathBuf {
+        self.cache.join(format!("aoc{}/input", day))
+    }
+
+    // Scaffolded days start out with an empty input file, which doesn't count
+    pub fn is_cached(&self, day: u32) -> bool {
+        fs::metadata(self.input_path(day)).is_ok_and(|m| m.is_file() && m.len() > 0)
+    }
+
+    // Path of the day's input, downloading it first unless it is cached
+    pub fn input(&self, day: u32) -> Result<PathBuf, Error> {
+        let path = self.input_path(day);
+        if self.is_cached(day) {
+            return Ok(path);
+        }
+        let text = self.get(&format!("/day/{}/input", day))?;
+        fs::create_dir_all(path.parent().unwrap())?;
+        // Write to a temporary file first so an interrupted download never looks cached
+        let tmp = path.with_extension("part");
+        fs::write(&tmp, text)?;
+        fs::rename(&tmp, &path)?;
+        Ok(path)
+    }
+
+    pub fn get(&self, path: &str) -> Result<String, Error> {
+        self.request(path, None)
+    }
+
+    // POST an `application/x-www-form-urlencoded` body
+    pub fn post(&self, path: &str, form: &str) -> Result<String, Error> {
+        self.request(path, Some(form))
+    }
+
+    fn request(&self, path: &str, form: Option<&str>) -> Result<String, Error> {
+        if self.session.is_empty() {
+            return Err(Error::new(ErrorKind::PermissionDenied, format!("no session token, set ${}", SESSION_VAR)));
+        }
+        self.throttle()?;
+        let url = format!("{}{}", self.base_url, path);
+        let mut curl = Command::new("curl");
+        // The cookie is passed on stdin so that it doesn't show up in the process list
+        curl.args(["--silent", "--show-error", "--header", "@-", "--user-agent", USER_AGENT, "--write-out", "\n%{http_code}"]);
+        if let Some(form) = form {
+            curl.args(["--data-binary", form]);
+        }
+        let mut child = curl.arg(&url)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|e| Error::new(e.kind(), format!("couldn't run curl: {}", e)))?;
+        writeln!(child.stdin.take().unwrap(), "Cookie: session={}", self.session)?;
+        let output = child.wait_with_output()?;
+        let _ = fs::create_dir_all(&self.cache).and_then(|_| fs::write(self.cache.join(STAMP_FILE), now_millis().to_string()));
+        if !output.status.success() {
+            return Err(Error::other(format!("{}: {}", url, String::from_utf8_lossy(&output.stderr).trim())));
+        }
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let (body, status) = stdout.rsplit_once('\n').unwrap_or(("", &stdout));
+        match status.trim() {
+            "200" => Ok(body.to_owned()),
+            "404" => Err(Error::new(ErrorKind::NotFound, format!("{}: not found, the puzzle may not be unlocked yet", url))),
+            s @ ("400" | "401" | "403" | "500") =>
+                Err(Error::new(ErrorKind::PermissionDenied, format!("{}: HTTP {}, check that ${} is a valid session", url, s, SESSION_VAR))),
+            s => Err(Error::other(format!("{}: HTTP {}", url, s))),
+        }
+    }
+
+    // Sleep until `interval` has passed since the last request made with this cache
+    fn throttle(&self) -> Result<(), Error> {
+        let last = fs::read_to_string(self.cache.join(STAMP_FILE))
+            .ok()
+            .and_then(|s| s.trim().parse::<u64>().ok());
+        if let Some(last) = last {
+            let elapsed = Duration::from_millis(now_millis().saturating_sub(last));
+            if elapsed < self.interval {
+                thread::sleep(self.interval - elapsed);
+            }
+        }
+        Ok(())
+    }
+}
+
+fn now_millis() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_millis() as u64).unwrap_or(0)
+}
+
+#[cfg(test)]
+mod client {
+    use super::*;
+    use super::mock::Server;
+    use std::time::Instant;
+
+    #[test]
+    fn fetch() {
+        let server = Server::start(|r| match (r.path.as_str(), r.cookie.as_deref()) {
+            ("/day/1/input", Some("session=secret")) => (200, "3   4\n4   3\n".to_owned()),
+            (_, Some("session=secret")) => (404, "Not Found".to_owned()),
+            _ => (400, "Puzzle inputs differ by user.".to_owned()),
+        });
+        let cache = env::temp_dir().join(format!("aoc2024-client-{}", std::process::id()));
+        let mut client = Client::new(&server.url, "secret", cache.clone());
+        client.interval = Duration::from_millis(300);
+
+        let start = Instant::now();
+        let path = client.input(1).unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), "3   4\n4   3\n");
+        assert_eq!(client.input(1).unwrap(), path);
+        assert_eq!(server.requests().len(), 1);
+
+        // The rate limit holds across clients sharing a cache
+        let other = Client { interval: Duration::from_millis(300), ..client.clone() };
+        let err = other.input(2).unwrap_err();
+        assert!(start.elapsed() >= Duration::from_millis(300));
+        assert_eq!(err.kind(), ErrorKind::NotFound);
+        assert!(!other.input_path(2).exists());
+
+        let anonymous = Client { session: "wrong".to_owned(), interval: Duration::ZERO, ..client.clone() };
+        let err = anonymous.input(3).unwrap_err();
+        fs::remove_dir_all(&cache).unwrap();
+        assert_eq!(err.kind(), ErrorKind::PermissionDenied);
+        let requests = server.requests();
+        assert_eq!(requests.len(), 3);
+        assert!(requests.iter().all(|r| r.method == "GET"));
+        assert_eq!(requests[0].cookie.as_deref(), Some("session=secret"));
+    }
+}
@@ -0,0 +1,230 @@
+use crate::client::Client;
+use regex::Regex;
+use std::fmt::{self, Display, Formatter};
+use std::fs::{self, OpenOptions};
+use std::io::{Error, ErrorKind, Write};
+use std::path::PathBuf;
+use std::time::Duration;
+
+// Every answer the server judged is logged in `aocN/submissions` next to the day's `answers`:
+//
+//     <part> <answer> <correct|incorrect|too-high|too-low>
+//
+// Answers that the log already rules out are never posted again. A correct answer is also
+// added to `answers` as the expected answer of `input`.
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Verdict {
+    Correct,
+    Incorrect,
+    TooHigh,
+    TooLow,
+    // Submitted too soon after the previous answer, nothing was judged
+    Wait(Duration),
+    // The part was already solved on the server
+    AlreadySolved,
+}
+
+impl Verdict {
+    // Whether the verdict is about the answer and so worth remembering
+    pub fn is_final(&self) -> bool {
+        matches!(self, Verdict::Correct | Verdict::Incorrect | Verdict::TooHigh | Verdict::TooLow)
+    }
+
+    fn from_word(word: &str) -> Option<Verdict> {
+        match word {
+            "correct" => Some(Verdict::Correct),
+            "incorrect" => Some(Verdict::Incorrect),
+            "too-high" => Some(Verdict::TooHigh),
+            "too-low" => Some(Verdict::TooLow),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::Incorrect => write!(f, "incorrect"),
+            Verdict::TooHigh => write!(f, "too-high"),
+            Verdict::TooLow => write!(f, "too-low"),
+            Verdict::Wait(d) => write!(f, "wait {}s", d.as_secs()),
+            Verdict::AlreadySolved => write!(f, "already-solved"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Submitted {
+    pub part: u32,
+    pub answer: String,
+    pub verdict: Verdict,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Submission {
+    pub verdict: Verdict,
+    // False when the verdict was taken from the submission log
+    pub posted: bool,
+}
+
+// Verdict of the `<article>` the server answers a submission with
+pub fn parse_response(html: &str) -> Result<Verdict, Error> {
+    if html.contains("That's the right answer") {
+        Ok(Verdict::Correct)
+    } else if html.contains("That's not the right answer") {
+        Ok(if html.contains("too high") {
+            Verdict::TooHigh
+        } else if html.contains("too low") {
+            Verdict::TooLow
+        } else {
+            Verdict::Incorrect
+        })
+    } else if html.contains("You gave an answer too recently") {
+        let re = Regex::new(r"You have (?:(\d+)m )?(\d+)s left to wait").unwrap();
+        let wait = re.captures(html)
+            .map(|c| {
+                let minutes = c.get(1).map_or(0, |m| m.as_str().parse::<u64>().unwrap());
+                Duration::from_secs(minutes * 60 + c[2].parse::<u64>().unwrap())
+            })
+            .unwrap_or(Duration::from_secs(60));
+        Ok(Verdict::Wait(wait))
+    } else if html.contains("You don't seem to be solving the right level") {
+        Ok(Verdict::AlreadySolved)
+    } else {
+        let text: String = html.chars().take(200).collect();
+        Err(Error::new(ErrorKind::InvalidData, format!("unrecognised response: {:?}", text)))
+    }
+}
+
+// What the log says about `answer` without asking the server: a logged verdict for it,
+// `Incorrect` once another answer was correct, or a bound from a numeric too-high/too-low
+pub fn known_verdict(log: &[Submitted], part: u32, answer: &str) -> Option<Verdict> {
+    let log: Vec<&Submitted> = log.iter().filter(|s| s.part == part).collect();
+    if let Some(s) = log.iter().find(|s| s.answer == answer) {
+        return Some(s.verdict.clone());
+    }
+    if log.iter().any(|s| s.verdict == Verdict::Correct) {
+        return Some(Verdict::Incorrect);
+    }
+    let value = answer.parse::<i128>().ok()?;
+    for s in log {
+        match (s.verdict.clone(), s.answer.parse::<i128>()) {
+            (Verdict::TooHigh, Ok(high)) if value >= high => return Some(Verdict::TooHigh),
+            (Verdict::TooLow, Ok(low)) if value <= low => return Some(Verdict::TooLow),
+            _ => (),
+        }
+    }
+    None
+}
+
+fn form_encode(s: &str) -> String {
+    s.bytes()
+        .map(|b| match b {
+            b'a'..=b'z' | b'A'..=b'Z' | b'0'..=b'9' | b'-' | b'_' | b'.' => (b as char).to_string(),
+            _ => format!("%{:02X}", b),
+        })
+        .collect()
+}
+
+impl Client {
+    fn record_file(&self, day: u32, name: &str) -> PathBuf {
+        self.records.join(format!("aoc{}/{}", day, name))
+    }
+
+    // A missing log means nothing was submitted yet
+    pub fn submissions(&self, day: u32) -> Result<Vec<Submitted>, Error> {
+        let path = self.record_file(day, "submissions");
+        let text = match fs::read_to_string(&path) {
+            Ok(t) => t,
+            Err(e) if e.kind() == ErrorKind::NotFound => return Ok(vec![]),
+            Err(e) => return Err(e),
+        };
+        text.lines()
+            .enumerate()
+            .filter(|(_, l)| !l.trim().is_empty() && !l.starts_with('#'))
+            .map(|(i, l)| {
+                let fields: Vec<&str> = l.split_whitespace().collect();
+                let submitted = match fields[..] {
+                    [part, answer, verdict] => part.parse().ok().zip(Verdict::from_word(verdict))
+                        .map(|(part, verdict)| Submitted { part, answer: answer.to_owned(), verdict }),
+                    _ => None,
+                };
+                submitted.ok_or_else(|| Error::new(ErrorKind::InvalidData,
+                    format!("{}: line {}: expected `<part> <answer> <verdict>`", path.display(), i + 1)))
+            })
+            .collect()
+    }
+
+    fn append(&self, day: u32, name: &str, line: &str) -> Result<(), Error> {
+        let path = self.record_file(day, name);
+        fs::create_dir_all(path.parent().unwrap())?;
+        let mut f = OpenOptions::new().create(true).append(true).open(path)?;
+        writeln!(f, "{}", line)
+    }
+
+    // Post `answer` for a part unless the submission log already has a verdict for it
+    pub fn submit(&self, day: u32, part: u32, answer: &str) -> Result<Submission, Error> {
+        let answer = answer.trim();
+        if answer.is_empty() || answer.contains(char::is_whitespace) {
+            return Err(Error::new(ErrorKind::InvalidInput, format!("can't submit {:?}", answer)));
+        }
+        if let Some(verdict) = known_verdict(&self.submissions(day)?, part, answer) {
+            return Ok(Submission { verdict, posted: false });
+        }
+        let html = self.post(&format!("/day/{}/answer", day), &format!("level={}&answer={}", part, form_encode(answer)))?;
+        let verdict = parse_response(&html)?;
+        if verdict.is_final() {
+            self.append(day, "submissions", &format!("{} {} {}", part, answer, verdict))?;
+        }
+        if verdict == Verdict::Correct {
+            let answers = fs::read_to_string(self.record_file(day, "answers")).unwrap_or_default();
+            let recorded = answers.lines().any(|l| l.split_whitespace().take(2).eq(["input", part.to_string().as_str()]));
+            if !recorded {
+                self.append(day, "answers", &format!("input {} {}", part, answer))?;
+            }
+        }
+        Ok(Submission { verdict, posted: true })
+    }
+}
+
+#[cfg(test)]
+mod submit {
+    use super::*;
+    use crate::client::mock::Server;
+
+    #[test]
+    fn bookkeeping() {
+        let server = Server::start(|r| (200, match r.body.as_str() {
+            "level=1&answer=42" => "<article><p>That's the right answer!  You are one gold star closer.</p></article>",
+            "level=1&answer=50" => "<article><p>That's not the right answer; your answer is too high.  Please wait one minute.</p></article>",
+            "level=1&answer=20" => "<article><p>You gave an answer too recently.  You have 1m 5s left to wait.</p></article>",
+            "level=2&answer=3%2C4" => "<article><p>That's not the right answer.  If you're stuck, ...</p></article>",
+            _ => "<html>?</html>",
+        }.to_owned()));
+        let records = std::env::temp_dir().join(format!("aoc2024-submit-{}", std::process::id()));
+        let mut client = Client::new(&server.url, "secret", records.clone());
+        client.interval = Duration::ZERO;
+
+        let verdicts: Vec<Submission> = [(1, "50"), (1, "50"), (1, "60"), (1, "20"), (1, "20"), (2, "3,4"), (1, "42"), (1, "43")]
+            .iter()
+            .map(|(part, answer)| client.submit(14, *part, answer).unwrap())
+            .collect();
+        let answers = fs::read_to_string(records.join("aoc14/answers")).unwrap();
+        let log = client.submissions(14).unwrap();
+        assert!(client.submit(14, 2, "7").is_err());
+        fs::remove_dir_all(&records).unwrap();
+
+        let expected = [
+            (Verdict::TooHigh, true), (Verdict::TooHigh, false), (Verdict::TooHigh, false),
+            (Verdict::Wait(Duration::from_secs(65)), true), (Verdict::Wait(Duration::from_secs(65)), true),
+            (Verdict::Incorrect, true), (Verdict::Correct, true), (Verdict::Incorrect, false),
+        ];
+        let verdicts: Vec<(Verdict, bool)> = verdicts.into_iter().map(|s| (s.verdict, s.posted)).collect();
+        assert_eq!(verdicts, expected);
+        assert_eq!(server.requests().len(), 6);
+        assert_eq!(answers, "input 1 42\n");
+        assert_eq!(log.iter().map(|s| s.answer.as_str()).collect::<Vec<_>>(), vec!["50", "3,4", "42"]);
+    }
+}
@@ -0,0 +1,154 @@
+use crate::runner::panic_message;
+use crate::utils::bench::{measure, BenchConfig, Stats};
+use crate::{Answer, Implementation, Solution};
+use std::fmt::{self, Display, Formatter};
+use std::panic::{self, AssertUnwindSafe};
+
+// Runs every implementation of a day's parts on one parsed input. The first implementation of a
+// part that answers is the reference the others have to agree with; each is then timed like a
+// phase of `bench`.
+
+// Name of the day's own `part1` and `part2`
+pub const DEFAULT: &str = "default";
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Agreement {
+    Reference,
+    Agrees,
+    // With the reference's answer
+    Differs(Answer),
+    Panicked(String),
+}
+
+impl Agreement {
+    pub fn is_ok(&self) -> bool {
+        matches!(self, Agreement::Reference | Agreement::Agrees)
+    }
+}
+
+impl Display for Agreement {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Agreement::Reference => write!(f, "reference"),
+            Agreement::Agrees => write!(f, "agrees"),
+            Agreement::Differs(expected) => write!(f, "DIFFERS (expected {})", expected),
+            Agreement::Panicked(msg) => write!(f, "PANIC ({})", msg),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Comparison {
+    pub part: u32,
+    pub name: &'static str,
+    pub answer: Option<Answer>,
+    pub agreement: Agreement,
+    // Only for implementations that answered
+    pub stats: Option<Stats>,
+}
+
+// The day's own parts followed by its alternatives, ordered by part
+pub fn implementations(solution: &dyn Solution) -> Vec<Implementation<'_>> {
+    let mut all = vec![
+        Implementation::new(DEFAULT, 1, || solution.part1()),
+        Implementation::new(DEFAULT, 2, || solution.part2()),
+    ];
+    all.extend(solution.alternatives());
+    all.sort_by_key(|i| i.part);
+    all
+}
+
+pub fn compare(solution: &dyn Solution, config: &BenchConfig) -> Vec<Comparison> {
+    let mut reference: [Option<Answer>; 2] = [None, None];
+    implementations(solution).iter()
+        .map(|imp| {
+            let answer = match panic::catch_unwind(AssertUnwindSafe(|| (imp.run)())) {
+                Ok(answer) => answer,
+                Err(payload) => {
+                    let agreement = Agreement::Panicked(panic_message(payload));
+                    return Comparison { part: imp.part, name: imp.name, answer: None, agreement, stats: None };
+                },
+            };
+            let agreement = match &reference[imp.part as usize - 1] {
+                None => {
+                    reference[imp.part as usize - 1] = Some(answer.clone());
+                    Agreement::Reference
+                },
+                Some(expected) if *expected == answer => Agreement::Agrees,
+                Some(expected) => Agreement::Differs(expected.clone()),
+            };
+            let stats = measure(config, || (), |_| (imp.run)());
+            Comparison { part: imp.part, name: imp.name, answer: Some(answer), agreement, stats: Some(stats) }
+        })
+        .collect()
+}
+
+// Tab separated `day part implementation answer agreement median min p95 samples` rows with
+// times in nanoseconds, `-` for implementations that panicked
+pub fn table(day: u32, comparisons: &[Comparison]) -> String {
+    comparisons.iter()
+        .map(|c| {
+            let answer = c.answer.as_ref().map_or("-".to_owned(), |a| a.to_string());
+            let times = match &c.stats {
+                Some(s) => format!("{}\t{}\t{}\t{}", s.median.as_nanos(), s.min.as_nanos(), s.p95.as_nanos(), s.samples),
+                None => "-\t-\t-\t-".to_owned(),
+            };
+            format!("{}\t{}\t{}\t{}\t{}\t{}\n", day, c.part, c.name, answer, c.agreement, times)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod compare {
+    use super::*;
+    use crate::AocError;
+
+    struct Sum(Vec<u32>);
+
+    impl Solution for Sum {
+        fn parse(inp: Vec<String>) -> Result<Self, AocError> {
+            Ok(Sum(inp.iter().map(|l| l.parse().unwrap()).collect()))
+        }
+
+        fn part1(&self) -> Answer {
+            Answer::from(self.0.iter().sum::<u32>())
+        }
+
+        fn part2(&self) -> Answer {
+            panic!("part 2 is broken")
+        }
+
+        fn alternatives(&self) -> Vec<Implementation<'_>> {
+            vec![
+                Implementation::new("rev", 1, || Answer::from(self.0.iter().rev().sum::<u32>())),
+                Implementation::new("max", 2, || Answer::from(*self.0.iter().max().unwrap())),
+                Implementation::new("last", 1, || Answer::from(*self.0.last().unwrap())),
+            ]
+        }
+    }
+
+    #[test]
+    fn agreement() {
+        let solution = Sum::parse(vec!["1".to_owned(), "2".to_owned()]).unwrap();
+        let config = BenchConfig { warmup: 0, iterations: 3, ..BenchConfig::default() };
+        let comparisons = compare(&solution, &config);
+        let rows: Vec<(u32, &str, Agreement)> = comparisons.iter().map(|c| (c.part, c.name, c.agreement.clone())).collect();
+        assert_eq!(rows, vec![
+            (1, DEFAULT, Agreement::Reference),
+            (1, "rev", Agreement::Agrees),
+            (1, "last", Agreement::Differs(Answer::from(3u32))),
+            (2, DEFAULT, Agreement::Panicked("part 2 is broken".to_owned())),
+            (2, "max", Agreement::Reference),
+        ]);
+        assert_eq!(comparisons[1].stats.unwrap().samples, 3);
+        let table = table(7, &comparisons);
+        assert!(table.starts_with("7\t1\tdefault\t3\treference\t"));
+        assert!(table.contains("7\t2\tdefault\t-\tPANIC (part 2 is broken)\t-\t-\t-\t-\n"));
+    }
+
+    #[test]
+    #[should_panic(expected = "days only have parts 1 and 2")]
+    fn part_out_of_range() {
+        Implementation::new("third", 3, || Answer::Unsolved);
+    }
+}
@@ -0,0 +1,237 @@
+use crate::utils::grid::Grid;
+use crate::utils::render::Palette;
+use std::io::{self, BufRead, Write};
+
+// Step-by-step viewer for grid simulations. Commands are read a line at a time, so it works in
+// any terminal and from a script. Simulations only step forward: going back replays them from
+// the start.
+
+pub trait Simulation {
+    // Back to the initial state
+    fn reset(&mut self);
+    // Advance one step, `None` once the simulation has finished
+    fn step(&mut self) -> Option<Event>;
+    // The current state, one character per cell
+    fn view(&self) -> Grid<char>;
+    // Colours of the characters of `view`
+    fn palette(&self) -> Palette<char>;
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Event {
+    // What happened in the step, e.g. the move made
+    pub label: String,
+    // What `until` can stop at, e.g. "blocked"
+    pub tags: Vec<&'static str>,
+}
+
+impl Event {
+    pub fn new(label: impl Into<String>) -> Event {
+        Event { label: label.into(), tags: vec![] }
+    }
+
+    pub fn tag(mut self, tag: &'static str, when: bool) -> Event {
+        if when {
+            self.tags.push(tag);
+        }
+        self
+    }
+}
+
+pub const HELP: &str = "commands:
+    n [K]         K steps forward (default 1, also an empty line)
+    b [K]         K steps back
+    g N           go to step N
+    u TAG...      run until a step tagged with all TAGs
+    q             quit";
+
+pub struct Debugger {
+    simulation: Box<dyn Simulation>,
+    // Steps taken since the start
+    index: usize,
+    last: Option<Event>,
+    finished: bool,
+}
+
+impl Debugger {
+    pub fn new(mut simulation: Box<dyn Simulation>) -> Debugger {
+        simulation.reset();
+        Debugger { simulation, index: 0, last: None, finished: false }
+    }
+
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    pub fn last(&self) -> Option<&Event> {
+        self.last.as_ref()
+    }
+
+    pub fn finished(&self) -> bool {
+        self.finished
+    }
+
+    // `false` if the simulation had already finished
+    pub fn forward(&mut self) -> bool {
+        if self.finished {
+            return false;
+        }
+        match self.simulation.step() {
+            Some(event) => {
+                self.index += 1;
+                self.last = Some(event);
+                true
+            },
+            None => {
+                self.finished = true;
+                false
+            },
+        }
+    }
+
+    // Step `n`, or the last step if the simulation finishes before
+    pub fn goto(&mut self, n: usize) {
+        if n < self.index {
+            self.simulation.reset();
+            self.index = 0;
+            self.last = None;
+            self.finished = false;
+        }
+        while self.index < n && self.forward() {}
+    }
+
+    pub fn back(&mut self, k: usize) {
+        self.goto(self.index.saturating_sub(k));
+    }
+
+    // Step at least once, up to the next step tagged with all of `tags`. `false` if the
+    // simulation finished first.
+    pub fn until(&mut self, tags: &[&str]) -> bool {
+        while self.forward() {
+            if tags.iter().all(|t| self.last.as_ref().unwrap().tags.contains(t)) {
+                return true;
+            }
+        }
+        false
+    }
+
+    // Current step and grid, coloured with ANSI escapes if `colour`
+    pub fn render(&self, colour: bool) -> String {
+        let mut out = match &self.last {
+            Some(e) if e.tags.is_empty() => format!("step {}: {}", self.index, e.label),
+            Some(e) => format!("step {}: {} [{}]", self.index, e.label, e.tags.join(" ")),
+            None => format!("step {}: start", self.index),
+        };
+        if self.finished {
+            out += " (finished)";
+        }
+        out += "\n";
+        let view = self.simulation.view();
+        if !colour {
+            return out + &view.to_string();
+        }
+        let palette = self.simulation.palette();
+        for r in 0..view.rows() {
+            let mut current = None;
+            for &c in view.row(r) {
+                let [red, green, blue] = palette.colour(&c);
+                if current != Some([red, green, blue]) {
+                    out += &format!("\x1b[38;2;{};{};{}m", red, green, blue);
+                    current = Some([red, green, blue]);
+                }
+                out.push(c);
+            }
+            out += "\x1b[0m\n";
+        }
+        out
+    }
+}
+
+// Read commands from `input` until `q` or the end of the input, showing the state after each.
+// `interactive` clears the screen before showing it and colours the grid.
+pub fn run(debugger: &mut Debugger, input: impl BufRead, output: &mut impl Write, interactive: bool) -> io::Result<()> {
+    let show = |debugger: &Debugger, output: &mut dyn Write, message: &str| -> io::Result<()> {
+        if interactive {
+            write!(output, "\x1b[H\x1b[2J")?;
+        }
+        write!(output, "{}{}> ", debugger.render(interactive), message)?;
+        output.flush()
+    };
+    show(debugger, output, "")?;
+    for line in input.lines() {
+        let line = line?;
+        let words: Vec<&str> = line.split_whitespace().collect();
+        let count = |default: usize| words.get(1).map_or(Some(default), |w| w.parse::<usize>().ok());
+        let mut message = String::new();
+        match (words.first().copied().unwrap_or("n"), count(1)) {
+            ("q", _) => return Ok(()),
+            ("n", Some(k)) => (0..k).for_each(|_| { debugger.forward(); }),
+            ("b", Some(k)) => debugger.back(k),
+            ("g", Some(n)) if words.len() == 2 => debugger.goto(n),
+            ("u", _) if words.len() > 1 => {
+                if !debugger.until(&words[1..]) {
+                    message = format!("no further step tagged {}\n", words[1..].join(" "));
+                }
+            },
+            ("h", _) | ("?", _) => message = format!("{}\n", HELP),
+            _ => message = format!("can't run `{}`, h for help\n", line.trim()),
+        }
+        show(debugger, output, &message)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod debugger {
+    use super::*;
+    use crate::utils::render::{RED, WHITE};
+
+    // A marker walking to the end of a corridor
+    struct Corridor {
+        len: usize,
+        pos: usize,
+    }
+
+    impl Simulation for Corridor {
+        fn reset(&mut self) {
+            self.pos = 0;
+        }
+
+        fn step(&mut self) -> Option<Event> {
+            if self.pos + 1 == self.len {
+                return None;
+            }
+            self.pos += 1;
+            Some(Event::new(format!("to {}", self.pos)).tag("end", self.pos + 1 == self.len).tag("even", self.pos.is_multiple_of(2)))
+        }
+
+        fn view(&self) -> Grid<char> {
+            Grid::from_vec(1, self.len, (0..self.len).map(|k| if k == self.pos { '@' } else { '.' }).collect())
+        }
+
+        fn palette(&self) -> Palette<char> {
+            Palette::new(WHITE).with('@', RED)
+        }
+    }
+
+    #[test]
+    fn stepping() {
+        let mut debugger = Debugger::new(Box::new(Corridor { len: 6, pos: 3 }));
+        assert_eq!(debugger.render(false), "step 0: start\n@.....\n");
+        debugger.goto(3);
+        debugger.back(1);
+        assert_eq!(debugger.render(false), "step 2: to 2 [even]\n..@...\n");
+        assert!(debugger.until(&["even"]));
+        assert_eq!(debugger.index(), 4);
+        assert!(!debugger.until(&["even"]));
+        assert!(debugger.finished() && debugger.last().unwrap().tags == vec!["end"]);
+        assert!(debugger.render(true).contains("\x1b[38;2;220;40;40m@\x1b[0m"));
+
+        let mut output = vec![];
+        let script = "n 2\n\nu end\nb 4\nu even odd\nx\nq\nn\n";
+        run(&mut Debugger::new(Box::new(Corridor { len: 6, pos: 0 })), script.as_bytes(), &mut output, false).unwrap();
+        let shown: Vec<String> = String::from_utf8(output).unwrap().split("> ").map(|s| s.lines().next().unwrap_or("").to_owned()).collect();
+        assert_eq!(shown, vec!["step 0: start", "step 2: to 2 [even]", "step 3: to 3", "step 5: to 5 [end]", "step 1: to 1",
+            "step 5: to 5 [end] (finished)", "step 5: to 5 [end] (finished)", ""]);
+    }
+}
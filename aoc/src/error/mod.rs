@@ -0,0 +1,82 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+// Lines and columns are stored 1-based, as an editor shows them. The constructors
+// take the 0-based indices the parsers loop over.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AocError {
+    EmptyInput,
+    UnexpectedEnd { line: usize, expected: String },
+    UnexpectedChar { line: usize, column: usize, found: char },
+    InvalidNumber { line: usize, column: usize, token: String },
+    Malformed { line: usize, reason: String },
+    Missing { expected: String },
+}
+
+impl AocError {
+    pub fn unexpected_end(i: usize, expected: &str) -> AocError {
+        AocError::UnexpectedEnd { line: i + 1, expected: expected.to_owned() }
+    }
+
+    pub fn unexpected_char(i: usize, j: usize, found: char) -> AocError {
+        AocError::UnexpectedChar { line: i + 1, column: j + 1, found }
+    }
+
+    pub fn invalid_number(i: usize, j: usize, token: &str) -> AocError {
+        AocError::InvalidNumber { line: i + 1, column: j + 1, token: token.to_owned() }
+    }
+
+    pub fn malformed(i: usize, reason: &str) -> AocError {
+        AocError::Malformed { line: i + 1, reason: reason.to_owned() }
+    }
+
+    pub fn missing(expected: &str) -> AocError {
+        AocError::Missing { expected: expected.to_owned() }
+    }
+}
+
+impl Display for AocError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AocError::EmptyInput => write!(f, "input is empty"),
+            AocError::UnexpectedEnd { line, expected } => write!(f, "line {}: input ended, expected {}", line, expected),
+            AocError::UnexpectedChar { line, column, found } => write!(f, "line {}, column {}: unexpected character {:?}", line, column, found),
+            AocError::InvalidNumber { line, column, token } => write!(f, "line {}, column {}: invalid number {:?}", line, column, token),
+            AocError::Malformed { line, reason } => write!(f, "line {}: {}", line, reason),
+            AocError::Missing { expected } => write!(f, "input has no {}", expected),
+        }
+    }
+}
+
+impl std::error::Error for AocError {}
+
+// Byte offset of `token` within `line`, if it is a slice of it
+fn column_of(line: &str, token: &str) -> usize {
+    let offset = (token.as_ptr() as usize).wrapping_sub(line.as_ptr() as usize);
+    if offset <= line.len() { offset } else { 0 }
+}
+
+// Parse `token`, a slice of line `i`, reporting where it sits in the line on failure
+pub fn parse_token<T: FromStr>(i: usize, line: &str, token: &str) -> Result<T, AocError> {
+    let token = token.trim();
+    token.parse::<T>().map_err(|_| AocError::invalid_number(i, column_of(line, token), token))
+}
+
+// Line `i` of the input, or an error naming what a truncated input was missing
+pub fn get_line<'a>(inp: &'a [String], i: usize, expected: &str) -> Result<&'a String, AocError> {
+    inp.get(i).ok_or_else(|| AocError::unexpected_end(i, expected))
+}
+
+#[cfg(test)]
+mod error {
+    use super::*;
+
+    #[test]
+    fn positions() {
+        let line = "p=0,4 v=3,x";
+        assert_eq!(parse_token::<i32>(0, line, &line[10..]), Err(AocError::InvalidNumber { line: 1, column: 11, token: "x".to_owned() }));
+        assert_eq!(parse_token::<i32>(0, line, " 3 "), Ok(3));
+        let inp = vec![line.to_owned()];
+        assert_eq!(get_line(&inp, 1, "velocity").unwrap_err().to_string(), "line 2: input ended, expected velocity");
+    }
+}
@@ -0,0 +1,106 @@
+pub mod utils;
+pub mod answer;
+pub mod answers;
+pub mod error;
+pub mod scaffold;
+pub mod client;
+pub mod runner;
+pub mod debugger;
+pub mod robustness;
+pub mod compare;
+
+pub use error::AocError;
+pub use answer::Answer;
+use utils::rng::Rng;
+use utils::render::Canvas;
+use debugger::Simulation;
+
+/// Common interface implemented by every day's `Context`.
+///
+/// Parts take `&self` so that both can be run, in any order, on a single parsed input.
+pub trait Solution {
+    fn parse(inp: Vec<String>) -> Result<Self, AocError> where Self: Sized;
+    fn part1(&self) -> Answer;
+    fn part2(&self) -> Answer;
+
+    /// Named pictures of the puzzle, e.g. the path a part found. None by default.
+    fn pictures(&self) -> Vec<(String, Canvas)> {
+        vec![]
+    }
+
+    /// Named frame sequences of a simulation, with the state after every `every`th step.
+    /// None by default.
+    fn animations(&self, _every: usize) -> Vec<(String, Vec<Canvas>)> {
+        vec![]
+    }
+
+    /// Named simulations to step through with `aoc2024 debug`. None by default.
+    fn simulations(&self) -> Vec<(String, Box<dyn Simulation>)> {
+        vec![]
+    }
+
+    /// Other ways to compute the parts, checked against `part1` and `part2` and timed next to
+    /// them by `aoc2024 compare`. None by default.
+    fn alternatives(&self) -> Vec<Implementation<'_>> {
+        vec![]
+    }
+}
+
+/// A named way to compute one part of a parsed input.
+pub struct Implementation<'a> {
+    pub name: &'static str,
+    pub part: u32,
+    pub run: Box<dyn Fn() -> Answer + 'a>,
+}
+
+impl<'a> Implementation<'a> {
+    pub fn new(name: &'static str, part: u32, run: impl Fn() -> Answer + 'a) -> Implementation<'a> {
+        assert!(part == 1 || part == 2, "implementation {} is for part {}, days only have parts 1 and 2", name, part);
+        Implementation { name, part, run: Box::new(run) }
+    }
+}
+
+/// A day's input, parsed into its `Solution`.
+pub type Parsed = Result<Box<dyn Solution>, AocError>;
+
+/// Registry entry for a single day.
+pub struct Day {
+    pub day: u32,
+    pub parse: fn(Vec<String>) -> Parsed,
+    pub generate: fn(&mut Rng) -> Vec<String>,
+}
+
+impl Day {
+    pub fn parse(&self, inp: Vec<String>) -> Parsed {
+        (self.parse)(inp)
+    }
+
+    /// Random valid input, the same for the same seed.
+    pub fn generate(&self, seed: u64) -> Vec<String> {
+        (self.generate)(&mut Rng::new(seed))
+    }
+}
+
+/// `parse` of a `Day` whose `Context` is `S`.
+pub fn boxed<S: Solution + 'static>(inp: Vec<String>) -> Parsed {
+    Ok(Box::new(S::parse(inp)?))
+}
+
+/// Registry of one year's days, with the directory holding their committed files.
+pub struct Year {
+    pub year: u32,
+    /// The year crate's `src`, with an `aocN` folder per day
+    pub dir: &'static str,
+    pub days: &'static [Day],
+}
+
+impl Year {
+    pub fn get_day(&self, day: u32) -> Option<&'static Day> {
+        self.days.iter().find(|d| d.day == day)
+    }
+}
+
+/// Look up `day` of `year` among the registered years.
+pub fn find_day(years: &[&'static Year], year: u32, day: u32) -> Option<&'static Day> {
+    years.iter().find(|y| y.year == year)?.get_day(day)
+}
@@ -0,0 +1,250 @@
+use crate::runner::panic_message;
+use crate::utils::rng::Rng;
+use crate::utils::{day_dirs, InputSource};
+use crate::{Day, Solution, Year};
+use std::fmt::{self, Display, Formatter};
+use std::fs;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+
+// Feeds damaged inputs to the parsers and parts of every day. Whatever the input, a day has to
+// reject it with an error or answer both parts; it must never panic or hang. Besides the day's
+// examples and generated inputs, the year can hand in fixed cases that broke a day before.
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Mutation {
+    // The sample as it is
+    Unchanged,
+    Empty,
+    // Cut off at a random character
+    Truncate,
+    // Spaces and tabs around lines, and blank lines at the end
+    Whitespace,
+    Crlf,
+    // A few characters replaced by random ones
+    RandomChars,
+    // A blank line somewhere in the middle
+    BlankLine,
+    // One line a character shorter or longer
+    Ragged,
+    // Without the first line and the first character of every line, the border of a walled map
+    Unwalled,
+    // One number replaced by one that overflows 32 or 64 bits
+    Huge,
+    // The last value of a line repeated dozens of times, e.g. `1: 2 3` to `1: 2 3 3 3 ...`
+    Long,
+}
+
+const HUGE: [&str; 5] = ["2147483647", "2147483648", "4294967296", "9223372036854775807", "99999999999999999999"];
+
+const NOISE: [char; 17] = ['0', '1', '7', '9', '-', '+', ',', ':', ' ', '\t', 'x', 'A', '#', '.', '@', '^', 'é'];
+
+impl Mutation {
+    pub const ALL: [Mutation; 11] = [
+        Mutation::Unchanged, Mutation::Empty, Mutation::Truncate, Mutation::Whitespace, Mutation::Crlf,
+        Mutation::RandomChars, Mutation::BlankLine, Mutation::Ragged, Mutation::Unwalled,
+        Mutation::Huge, Mutation::Long,
+    ];
+
+    // Whether the seed changes the result
+    pub fn is_random(self) -> bool {
+        !matches!(self, Mutation::Unchanged | Mutation::Empty | Mutation::Crlf | Mutation::Unwalled)
+    }
+
+    pub fn apply(self, text: &str, rng: &mut Rng) -> String {
+        let mut lines: Vec<String> = text.lines().map(|l| l.to_owned()).collect();
+        if lines.is_empty() {
+            lines.push(String::new());
+        }
+        let k = rng.below(lines.len());
+        match self {
+            Mutation::Unchanged => return text.to_owned(),
+            Mutation::Empty => return String::new(),
+            Mutation::Truncate => return text.chars().take(rng.below(text.chars().count() + 1)).collect(),
+            Mutation::Crlf => return text.replace('\n', "\r\n"),
+            Mutation::Whitespace => {
+                for line in &mut lines {
+                    if rng.chance(0.3) {
+                        *line = format!("{}{}", *rng.pick(&[" ", "\t", "  "]), line);
+                    }
+                    if rng.chance(0.3) {
+                        line.push(*rng.pick(&[' ', '\t']));
+                    }
+                }
+                lines.extend((0..rng.range(0, 2)).map(|_| String::new()));
+            },
+            Mutation::RandomChars => {
+                let mut chars: Vec<char> = text.chars().collect();
+                for _ in 0..rng.range(1, 3) {
+                    if !chars.is_empty() {
+                        let i = rng.below(chars.len());
+                        chars[i] = *rng.pick(&NOISE);
+                    }
+                }
+                return chars.into_iter().collect();
+            },
+            Mutation::BlankLine => lines.insert(k, String::new()),
+            Mutation::Ragged => {
+                if rng.chance(0.5) || lines[k].is_empty() {
+                    let c = lines[k].chars().last().unwrap_or('.');
+                    lines[k].push(c);
+                } else {
+                    lines[k].pop();
+                }
+            },
+            Mutation::Huge => {
+                let numbers: Vec<(usize, usize)> = text.char_indices()
+                    .filter(|&(i, c)| c.is_ascii_digit() && !text[..i].ends_with(|p: char| p.is_ascii_digit()))
+                    .map(|(i, _)| (i, text[i..].find(|c: char| !c.is_ascii_digit()).map_or(text.len(), |n| i + n)))
+                    .collect();
+                if numbers.is_empty() {
+                    return text.to_owned();
+                }
+                let (start, end) = numbers[rng.below(numbers.len())];
+                return format!("{}{}{}", &text[..start], rng.pick(&HUGE), &text[end..]);
+            },
+            Mutation::Long => {
+                let sep = lines[k].rfind([' ', ',']).map(|i| lines[k][i..].to_owned());
+                if let Some(last) = sep {
+                    lines[k] += &last.repeat(rng.range(20, 40) as usize);
+                }
+            },
+            Mutation::Unwalled => {
+                if lines.len() > 1 {
+                    lines.remove(0);
+                }
+                for line in &mut lines {
+                    if !line.is_empty() {
+                        line.remove(0);
+                    }
+                }
+            },
+        }
+        lines.iter().map(|l| format!("{}\n", l)).collect()
+    }
+}
+
+impl Display for Mutation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", format!("{:?}", self).to_lowercase())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Outcome {
+    Answered,
+    Rejected(String),
+    Panicked(String),
+    TimedOut,
+}
+
+impl Outcome {
+    pub fn is_ok(&self) -> bool {
+        matches!(self, Outcome::Answered | Outcome::Rejected(_))
+    }
+}
+
+// Read `text` like an input file and run the day on it, giving up after `limit`. A day that
+// hangs is left running in its thread.
+pub fn probe(day: &'static Day, text: &str, limit: Duration) -> Outcome {
+    let (tx, rx) = mpsc::channel();
+    let text = text.to_owned();
+    thread::spawn(move || {
+        let outcome = panic::catch_unwind(AssertUnwindSafe(|| {
+            let solution: Box<dyn Solution> = match day.parse(InputSource::Text(text).read().unwrap()) {
+                Ok(s) => s,
+                Err(e) => return Outcome::Rejected(e.to_string()),
+            };
+            solution.part1();
+            solution.part2();
+            Outcome::Answered
+        }));
+        let _ = tx.send(outcome.unwrap_or_else(|payload| Outcome::Panicked(panic_message(payload))));
+    });
+    rx.recv_timeout(limit).unwrap_or(Outcome::TimedOut)
+}
+
+// The day's example files and a few generated inputs, by name
+pub fn samples(year: &Year, day: &Day, generated: u64) -> Vec<(String, String)> {
+    let mut samples: Vec<(String, String)> = day_dirs(year, day.day).iter()
+        .filter_map(|dir| fs::read_dir(dir).ok())
+        .flatten()
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.file_name().to_string_lossy().starts_with("example"))
+        .filter_map(|entry| Some((entry.file_name().to_string_lossy().into_owned(), fs::read_to_string(entry.path()).ok()?)))
+        .collect();
+    samples.sort();
+    samples.dedup_by(|a, b| a.0 == b.0);
+    samples.extend((0..generated).map(|seed| (format!("generated {}", seed), day.generate(seed).join("\n") + "\n")));
+    samples
+}
+
+#[derive(Debug, Clone)]
+pub struct Failure {
+    pub day: u32,
+    pub sample: String,
+    pub mutation: Mutation,
+    pub seed: u64,
+    pub text: String,
+    pub outcome: Outcome,
+}
+
+impl Display for Failure {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "day {} {} {} (seed {}): {:?}", self.day, self.sample, self.mutation, self.seed, self.outcome)
+    }
+}
+
+// Every mutation of every sample and of `cases` with `seeds` seeds each
+pub fn check_day(year: &Year, day: &'static Day, cases: &[&str], seeds: u64, limit: Duration) -> Vec<Failure> {
+    let mut failures = vec![];
+    let mut samples = samples(year, day, 3);
+    samples.extend(cases.iter().enumerate().map(|(k, text)| (format!("case {}", k + 1), format!("{}\n", text))));
+    for (sample, text) in samples {
+        for mutation in Mutation::ALL {
+            for seed in 0..if mutation.is_random() { seeds } else { 1 } {
+                let text = mutation.apply(&text, &mut Rng::new(seed));
+                let outcome = probe(day, &text, limit);
+                if !outcome.is_ok() {
+                    failures.push(Failure { day: day.day, sample: sample.clone(), mutation, seed, text, outcome });
+                }
+            }
+        }
+    }
+    failures
+}
+
+// Every day of `year` with its `(day, input)` cases, one day at a time: the days use rayon
+// themselves, and probing them from the rayon pool would starve them
+pub fn check_year(year: &Year, cases: &[(u32, &str)], seeds: u64, limit: Duration) -> Vec<Failure> {
+    year.days.iter()
+        .flat_map(|day| {
+            let cases: Vec<&str> = cases.iter().filter(|(d, _)| *d == day.day).map(|(_, text)| *text).collect();
+            check_day(year, day, &cases, seeds, limit)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod robustness {
+    use super::*;
+
+    #[test]
+    fn mutations() {
+        let text = "ab\ncd\n";
+        let mut rng = Rng::new(1);
+        assert_eq!(Mutation::Crlf.apply(text, &mut rng), "ab\r\ncd\r\n");
+        assert_eq!(Mutation::Empty.apply(text, &mut rng), "");
+        assert!(text.starts_with(&Mutation::Truncate.apply(text, &mut rng)));
+        assert_eq!(Mutation::BlankLine.apply(text, &mut rng).lines().filter(|l| l.is_empty()).count(), 1);
+        assert_eq!(Mutation::Ragged.apply(text, &mut rng).len().abs_diff(text.len()), 1);
+        assert_eq!(Mutation::Unwalled.apply(text, &mut rng), "d\n");
+        assert_eq!(Mutation::Unchanged.apply(text, &mut rng), text);
+        let huge = Mutation::Huge.apply("1: 23 4\n", &mut rng);
+        assert!(HUGE.iter().any(|h| [format!("{}: 23 4\n", h), format!("1: {} 4\n", h), format!("1: 23 {}\n", h)].contains(&huge)), "{}", huge);
+        let long = Mutation::Long.apply("1: 2 3\n", &mut rng);
+        assert!(long.starts_with("1: 2 3 3 3 3") && long.len() > 40, "{}", long);
+    }
+}
@@ -0,0 +1,171 @@
+use crate::answers::{expected_answer, read_answers, Expected};
+use crate::utils::{day_file, InputSource};
+use crate::{Answer, Day, Solution, Year};
+use rayon::prelude::*;
+use std::any::Any;
+use std::fmt::{self, Display, Formatter};
+use std::panic::{self, AssertUnwindSafe};
+use std::time::{Duration, Instant};
+
+// Runs every registered day of a year on its `input`, days in parallel. Parsing and each part are run
+// under `catch_unwind`, so a day that panics only fails its own rows.
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Status {
+    Pass,
+    Fail(Answer),
+    // No answer recorded for the input
+    Unchecked,
+    Panicked(String),
+    // The input couldn't be read or parsed
+    Error(String),
+}
+
+impl Status {
+    pub fn is_ok(&self) -> bool {
+        matches!(self, Status::Pass | Status::Unchecked)
+    }
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Status::Pass => write!(f, "pass"),
+            Status::Fail(expected) => write!(f, "FAIL (expected {})", expected),
+            Status::Unchecked => write!(f, "unchecked"),
+            Status::Panicked(msg) => write!(f, "PANIC ({})", msg),
+            Status::Error(msg) => write!(f, "ERROR ({})", msg),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PartRun {
+    pub part: u32,
+    pub answer: Option<Answer>,
+    pub status: Status,
+    pub time: Duration,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DayRun {
+    pub day: u32,
+    pub parse: Duration,
+    // Both parts, or a single row with part 0 when the day failed before running them
+    pub parts: Vec<PartRun>,
+}
+
+impl DayRun {
+    pub fn time(&self) -> Duration {
+        self.parse + self.parts.iter().map(|p| p.time).sum::<Duration>()
+    }
+
+    fn failed(day: u32, parse: Duration, status: Status) -> DayRun {
+        DayRun { day, parse, parts: vec![PartRun { part: 0, answer: None, status, time: Duration::ZERO }] }
+    }
+}
+
+pub(crate) fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    payload.downcast_ref::<&str>().map(|s| s.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "unknown panic".to_owned())
+}
+
+fn timed<R>(f: impl FnOnce() -> R) -> (Result<R, String>, Duration) {
+    let t0 = Instant::now();
+    let ret = panic::catch_unwind(AssertUnwindSafe(f)).map_err(panic_message);
+    (ret, t0.elapsed())
+}
+
+// Parse `inp` and run both parts, checking them against `answers` recorded for `file`
+pub fn run_day(day: &Day, inp: Vec<String>, answers: &[Expected], file: &str) -> DayRun {
+    let (solution, parse) = timed(|| day.parse(inp));
+    let solution: Box<dyn Solution> = match solution {
+        Ok(Ok(s)) => s,
+        Ok(Err(e)) => return DayRun::failed(day.day, parse, Status::Error(e.to_string())),
+        Err(msg) => return DayRun::failed(day.day, parse, Status::Panicked(msg)),
+    };
+    let parts = [1, 2].iter()
+        .map(|&part| {
+            let (answer, time) = timed(|| if part == 1 { solution.part1() } else { solution.part2() });
+            let status = match (&answer, expected_answer(answers, file, part)) {
+                (Err(msg), _) => Status::Panicked(msg.clone()),
+                (Ok(_), None) => Status::Unchecked,
+                (Ok(a), Some(expected)) if *a == expected => Status::Pass,
+                (Ok(_), Some(expected)) => Status::Fail(expected),
+            };
+            PartRun { part, answer: answer.ok(), status, time }
+        })
+        .collect();
+    DayRun { day: day.day, parse, parts }
+}
+
+// Every day of `year` on its `input`, in day order
+pub fn run_all(year: &Year) -> Vec<DayRun> {
+    year.days.par_iter()
+        .map(|day| {
+            let source = InputSource::File(day_file(year, day.day, "input"));
+            let inp = match source.read() {
+                Ok(inp) => inp,
+                Err(e) => return DayRun::failed(day.day, Duration::ZERO, Status::Error(format!("{}: {}", source, e))),
+            };
+            match panic::catch_unwind(|| read_answers(year, day.day)) {
+                Ok(answers) => run_day(day, inp, &answers, "input"),
+                Err(payload) => DayRun::failed(day.day, Duration::ZERO, Status::Panicked(panic_message(payload))),
+            }
+        })
+        .collect()
+}
+
+// Tab separated `day part answer status ms` rows and a closing `total` row with the number of
+// parts that passed and the summed time
+pub fn table(runs: &Vec<DayRun>) -> String {
+    let mut out = String::new();
+    for run in runs {
+        for p in &run.parts {
+            let answer = p.answer.as_ref().map_or("-".to_owned(), |a| a.to_string());
+            out += &format!("{}\t{}\t{}\t{}\t{:.3}\n", run.day, p.part, answer, p.status, p.time.as_secs_f64() * 1e3);
+        }
+    }
+    let parts: Vec<&PartRun> = runs.iter().flat_map(|r| &r.parts).collect();
+    let passed = parts.iter().filter(|p| p.status == Status::Pass).count();
+    let total: Duration = runs.iter().map(|r| r.time()).sum();
+    out += &format!("total\t\t\t{}/{} passed\t{:.3}\n", passed, parts.len(), total.as_secs_f64() * 1e3);
+    out
+}
+
+#[cfg(test)]
+mod runner {
+    use super::*;
+    use crate::AocError;
+
+    struct Flaky;
+
+    impl Solution for Flaky {
+        fn parse(inp: Vec<String>) -> Result<Self, AocError> {
+            if inp.is_empty() { Err(AocError::EmptyInput) } else { Ok(Flaky) }
+        }
+
+        fn part1(&self) -> Answer {
+            Answer::from(7u32)
+        }
+
+        fn part2(&self) -> Answer {
+            panic!("part 2 is broken")
+        }
+    }
+
+    #[test]
+    fn isolation() {
+        let day = Day { day: 99, parse: crate::boxed::<Flaky>, generate: |_| vec![] };
+        let answers = vec![Expected { file: "input".to_owned(), part: 1, answer: Answer::from(7u32) }];
+        let run = run_day(&day, vec!["x".to_owned()], &answers, "input");
+        assert_eq!(run.parts[0].status, Status::Pass);
+        assert_eq!(run.parts[1].status, Status::Panicked("part 2 is broken".to_owned()));
+        let failed = run_day(&day, vec![], &answers, "input");
+        assert!(matches!(failed.parts[..], [PartRun { part: 0, status: Status::Error(_), .. }]));
+        let table = table(&vec![run, failed]);
+        assert!(table.starts_with("99\t1\t7\tpass\t"));
+        assert!(table.lines().last().unwrap().starts_with("total\t\t\t1/3 passed\t"));
+    }
+}
@@ -0,0 +1,199 @@
+use std::fs;
+use std::io::{Error, ErrorKind};
+use std::path::{Path, PathBuf};
+
+// Generates `src/aocN/` for a new day in a year crate and registers it in the crate's `src/lib.rs`.
+
+const TEMPLATE: &str = r#"use crate::read_day;
+use aoc::utils::bench;
+use aoc::utils::prop;
+use aoc::utils::rng::Rng;
+use aoc::Answer;
+use aoc::error::AocError;
+
+#[derive(Debug)]
+pub struct Context {
+    lines: Vec<String>,
+}
+
+impl Context {
+    pub fn new(inp: Vec<String>) -> Result<Context, AocError> {
+        Ok(Context {
+            lines: inp
+        })
+    }
+
+    pub fn part1(&self) -> usize {
+        0
+    }
+
+    pub fn part2(&self) -> usize {
+        0
+    }
+}
+
+// Random valid input for the property tests
+pub fn generate(rng: &mut Rng) -> Vec<String> {
+    vec![]
+}
+
+impl aoc::Solution for Context {
+    fn parse(inp: Vec<String>) -> Result<Self, AocError> {
+        Context::new(inp)
+    }
+
+    fn part1(&self) -> Answer {
+        Context::part1(self).into()
+    }
+
+    fn part2(&self) -> Answer {
+        Context::part2(self).into()
+    }
+}
+
+#[allow(unused_imports)]
+#[cfg(test)]
+mod aoc{day} {
+    use super::*;
+{examples}
+    #[test]
+    fn properties() {
+        prop::check(20, generate, |inp| {
+            let context = Context::new(inp).unwrap();
+            assert_eq!(context.part1(), context.part1());
+        });
+    }
+
+    #[test]
+    fn actual() {
+        let text: Vec<String> = read_day({day}, "input").expect("couldn't read input - aoc{day}");
+        let context = Context::new(text).unwrap();
+        println!("Part1: {:?}", bench(|| context.part1(), Some("aoc{day}: part1")));
+        println!("Part2: {:?}", bench(|| context.part2(), Some("aoc{day}: part2")));
+    }
+}
+"#;
+
+const EXAMPLE_TEST: &str = r#"
+    #[test]
+    fn {test}() {
+        let text: Vec<String> = read_day({day}, "{file}").expect("couldn't read input - aoc{day}");
+        let context = Context::new(text).unwrap();
+        println!("{label}Part1: {:?}", context.part1());
+        println!("{label}Part2: {:?}", context.part2());
+    }
+"#;
+
+// With one example the file is called `example`, otherwise `example1`..`exampleN`
+pub fn example_files(examples: usize) -> Vec<String> {
+    match examples {
+        1 => vec!["example".to_owned()],
+        n => (1..=n).map(|i| format!("example{}", i)).collect(),
+    }
+}
+
+pub fn render(day: u32, examples: usize) -> String {
+    let tests: String = example_files(examples).iter()
+        .enumerate()
+        .map(|(i, file)| {
+            let label = if examples == 1 { String::new() } else { format!("Example{} ", i + 1) };
+            EXAMPLE_TEST
+                .replace("{test}", &format!("example{}", i + 1))
+                .replace("{file}", file)
+                .replace("{label}", &label)
+        })
+        .collect();
+    TEMPLATE.replace("{examples}", &tests).replace("{day}", &day.to_string())
+}
+
+// A day module declaration, `mod aocN;` on a line of its own
+fn is_day_module(line: &str) -> bool {
+    line.strip_prefix("mod aoc")
+        .and_then(|rest| rest.strip_suffix(';'))
+        .is_some_and(|n| !n.is_empty() && n.chars().all(|c| c.is_ascii_digit()))
+}
+
+// Add `mod aocN;` after the last day module and an entry at the end of `DAYS`
+fn register(lib: &str, day: u32) -> Result<String, Error> {
+    let invalid = |msg: &str| Error::new(ErrorKind::InvalidData, format!("src/lib.rs: {}", msg));
+    let module = format!("mod aoc{};", day);
+    if lib.lines().any(|l| l.trim() == module) {
+        return Err(Error::new(ErrorKind::AlreadyExists, format!("aoc{} is already declared in src/lib.rs", day)));
+    }
+    let mut lines: Vec<String> = lib.lines().map(|l| l.to_owned()).collect();
+    let last_mod = lines.iter()
+        .rposition(|l| is_day_module(l))
+        .ok_or_else(|| invalid("no `mod aocN;` declarations"))?;
+    lines.insert(last_mod + 1, String::new());
+    lines.insert(last_mod + 2, module);
+    let start = lines.iter()
+        .position(|l| l.starts_with("pub const DAYS"))
+        .ok_or_else(|| invalid("no `DAYS` registry"))?;
+    let end = start + lines[start..].iter()
+        .position(|l| l.trim() == "];")
+        .ok_or_else(|| invalid("unterminated `DAYS` registry"))?;
+    lines.insert(end, format!("    Day {{ day: {}, parse: boxed::<aoc{}::Context>, generate: aoc{}::generate }},", day, day, day));
+    Ok(lines.join("\n") + "\n")
+}
+
+// Create the module, example, input and answers files of a day under `root/src`, where `root` is
+// the year crate, and register it.
+// Refuses to touch a day that already exists.
+pub fn create_day(root: &Path, day: u32, examples: usize) -> Result<Vec<PathBuf>, Error> {
+    if day == 0 || examples == 0 {
+        return Err(Error::new(ErrorKind::InvalidInput, "day and number of examples must be at least 1"));
+    }
+    let dir = root.join("src").join(format!("aoc{}", day));
+    if dir.exists() {
+        return Err(Error::new(ErrorKind::AlreadyExists, format!("{} already exists", dir.display())));
+    }
+    let lib_path = root.join("src/lib.rs");
+    let lib = register(&fs::read_to_string(&lib_path)?, day)?;
+
+    fs::create_dir_all(&dir)?;
+    let mut created = vec![];
+    let mut files: Vec<(String, String)> = example_files(examples).into_iter().map(|f| (f, String::new())).collect();
+    files.push(("input".to_owned(), String::new()));
+    files.push(("answers".to_owned(), String::new()));
+    files.push(("mod.rs".to_owned(), render(day, examples)));
+    for (name, contents) in files {
+        let path = dir.join(name);
+        fs::write(&path, contents)?;
+        created.push(path);
+    }
+    fs::write(&lib_path, lib)?;
+    created.push(lib_path);
+    Ok(created)
+}
+
+#[cfg(test)]
+mod scaffold {
+    use super::*;
+    use crate::utils::workspace_dir;
+
+    #[test]
+    fn new_day() {
+        let root = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        fs::create_dir_all(root.join("src")).unwrap();
+        // The year crate's own registry, which ends in a test module named like a day
+        let lib = fs::read_to_string(workspace_dir().join("aoc2024/src/lib.rs")).unwrap();
+        fs::write(root.join("src/lib.rs"), &lib).unwrap();
+
+        let created = create_day(&root, 25, 2).unwrap();
+        let names: Vec<String> = created.iter().map(|p| p.file_name().unwrap().to_string_lossy().into_owned()).collect();
+        assert_eq!(names, vec!["example1", "example2", "input", "answers", "mod.rs", "lib.rs"]);
+        let new_lib = fs::read_to_string(root.join("src/lib.rs")).unwrap();
+        let declared = new_lib.find("\nmod aoc25;\n").unwrap();
+        assert!(declared < new_lib.find("pub const DAYS").unwrap());
+        assert!(new_lib.lines().take_while(|l| *l != "mod aoc25;").filter(|l| is_day_module(l)).count() > 1);
+        assert_eq!(new_lib.lines().rfind(|l| l.starts_with("mod aoc")), Some("mod aoc2024 {"));
+        assert!(new_lib.contains("    Day { day: 25, parse: boxed::<aoc25::Context>, generate: aoc25::generate },\n];"));
+        let module = fs::read_to_string(root.join("src/aoc25/mod.rs")).unwrap();
+        assert!(module.contains("read_day(25, \"example2\")"));
+        assert!(module.contains("impl aoc::Solution for Context"));
+
+        let err = create_day(&root, 25, 1).unwrap_err();
+        fs::remove_dir_all(&root).unwrap();
+        assert_eq!(err.kind(), ErrorKind::AlreadyExists);
+    }
+}
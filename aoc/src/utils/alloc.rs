@@ -0,0 +1,121 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicBool, AtomicIsize, AtomicUsize, Ordering::Relaxed};
+
+// Counting wrapper around the system allocator. The binary installs it as the global allocator;
+// it only counts inside `track`, so it costs one atomic load per allocation otherwise.
+// Counters are process wide: allocations of rayon workers are included, and `track` calls must
+// not overlap.
+
+pub struct CountingAlloc;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static BYTES: AtomicUsize = AtomicUsize::new(0);
+// Signed, since memory allocated before tracking started may be freed during it
+static LIVE: AtomicIsize = AtomicIsize::new(0);
+static PEAK: AtomicIsize = AtomicIsize::new(0);
+
+fn allocated(size: usize) {
+    ALLOCATIONS.fetch_add(1, Relaxed);
+    BYTES.fetch_add(size, Relaxed);
+    let live = LIVE.fetch_add(size as isize, Relaxed) + size as isize;
+    PEAK.fetch_max(live, Relaxed);
+}
+
+fn freed(size: usize) {
+    LIVE.fetch_sub(size as isize, Relaxed);
+}
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let p = System.alloc(layout);
+        if !p.is_null() && ENABLED.load(Relaxed) {
+            allocated(layout.size());
+        }
+        p
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let p = System.alloc_zeroed(layout);
+        if !p.is_null() && ENABLED.load(Relaxed) {
+            allocated(layout.size());
+        }
+        p
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        if ENABLED.load(Relaxed) {
+            freed(layout.size());
+        }
+    }
+
+    // Counted as a new allocation of the new size replacing the old one
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let p = System.realloc(ptr, layout, new_size);
+        if !p.is_null() && ENABLED.load(Relaxed) {
+            freed(layout.size());
+            allocated(new_size);
+        }
+        p
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct AllocStats {
+    pub allocations: usize,
+    // Total requested, including memory freed again
+    pub bytes: usize,
+    // Highest amount live at once, relative to the start
+    pub peak: usize,
+}
+
+impl AllocStats {
+    pub fn to_json(&self) -> String {
+        format!("{{\"allocations\":{},\"bytes\":{},\"peak_bytes\":{}}}", self.allocations, self.bytes, self.peak)
+    }
+}
+
+// Run `f` and count what it allocates. All zero unless `CountingAlloc` is the global allocator.
+pub fn track<R>(f: impl FnOnce() -> R) -> (R, AllocStats) {
+    ALLOCATIONS.store(0, Relaxed);
+    BYTES.store(0, Relaxed);
+    LIVE.store(0, Relaxed);
+    PEAK.store(0, Relaxed);
+    ENABLED.store(true, Relaxed);
+    let ret = f();
+    ENABLED.store(false, Relaxed);
+    let stats = AllocStats {
+        allocations: ALLOCATIONS.load(Relaxed),
+        bytes: BYTES.load(Relaxed),
+        peak: PEAK.load(Relaxed).max(0) as usize,
+    };
+    (ret, stats)
+}
+
+// Whether `CountingAlloc` is the global allocator of this process
+pub fn installed() -> bool {
+    let (_, stats) = track(|| std::hint::black_box(Box::new(0u64)));
+    stats.allocations > 0
+}
+
+#[cfg(test)]
+mod alloc {
+    use super::*;
+
+    #[global_allocator]
+    static ALLOC: CountingAlloc = CountingAlloc;
+
+    #[test]
+    fn counting() {
+        assert!(installed());
+        let (v, stats) = track(|| {
+            let mut v: Vec<u64> = Vec::with_capacity(100);
+            v.extend(0..100);
+            v.into_iter().map(|x| x.to_string()).collect::<Vec<String>>()
+        });
+        assert_eq!(v.len(), 100);
+        assert!(stats.allocations >= 102, "{:?}", stats);
+        assert!(stats.bytes >= 800 + 100 * 24, "{:?}", stats);
+    }
+}
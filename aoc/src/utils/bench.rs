@@ -0,0 +1,185 @@
+use crate::{AocError, Day};
+use crate::utils::alloc::{self, AllocStats};
+use std::time::{Duration, Instant};
+
+// Single-shot timing of a closure, printing how long it took. Used by the per-day tests.
+pub fn bench<F, R>(f: F, identifier: Option<&str>) -> R
+where
+    F: FnOnce() -> R,
+{
+    let t0 = Instant::now();
+    let ret = f();
+    let time_elapsed = Instant::now().duration_since(t0);
+    match identifier {
+        Some(v) => println!("time used for {} -> {:?}", v, time_elapsed),
+        None => println!("time used -> {:?}", time_elapsed)
+    };
+    ret
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BenchConfig {
+    // Untimed runs before sampling starts
+    pub warmup: usize,
+    // Maximum number of timed runs per phase
+    pub iterations: usize,
+    // Sampling of a phase stops early once this much time was spent on it
+    pub max_time: Duration,
+    // Also run each phase once more under `alloc::track`
+    pub memory: bool,
+}
+
+impl Default for BenchConfig {
+    fn default() -> Self {
+        BenchConfig {
+            warmup: 1,
+            iterations: 20,
+            max_time: Duration::from_secs(2),
+            memory: false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stats {
+    pub samples: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+    pub mean: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(mut samples: Vec<Duration>) -> Stats {
+        assert!(!samples.is_empty(), "no samples to compute statistics from");
+        samples.sort();
+        let n = samples.len();
+        let median = if n % 2 == 1 {
+            samples[n / 2]
+        } else {
+            (samples[n / 2 - 1] + samples[n / 2]) / 2
+        };
+        // Nearest-rank percentile
+        let p95 = samples[(n * 95).div_ceil(100).max(1) - 1];
+        Stats {
+            samples: n,
+            min: samples[0],
+            median,
+            p95,
+            mean: samples.iter().sum::<Duration>() / n as u32,
+        }
+    }
+
+    pub fn to_json(&self) -> String {
+        format!(
+            "{{\"samples\":{},\"min_ns\":{},\"median_ns\":{},\"p95_ns\":{},\"mean_ns\":{}}}",
+            self.samples, self.min.as_nanos(), self.median.as_nanos(), self.p95.as_nanos(), self.mean.as_nanos()
+        )
+    }
+}
+
+// Time `f` on a fresh value from `setup` for every run; only `f` is measured
+pub fn measure<S, T, R>(config: &BenchConfig, mut setup: S, mut f: impl FnMut(T) -> R) -> Stats
+where
+    S: FnMut() -> T,
+{
+    for _ in 0..config.warmup {
+        f(setup());
+    }
+    let mut samples = vec![];
+    let start = Instant::now();
+    while samples.len() < config.iterations.max(1) {
+        let inp = setup();
+        let t0 = Instant::now();
+        let ret = f(inp);
+        samples.push(t0.elapsed());
+        drop(ret);
+        if start.elapsed() >= config.max_time {
+            break;
+        }
+    }
+    Stats::from_samples(samples)
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DayBench {
+    pub day: u32,
+    pub parse: Stats,
+    pub part1: Stats,
+    pub part2: Stats,
+    // Allocations of parse, part1 and part2 when `BenchConfig::memory` is set
+    pub memory: Option<[AllocStats; 3]>,
+}
+
+impl DayBench {
+    pub fn phases(&self) -> [(&'static str, &Stats); 3] {
+        [("parse", &self.parse), ("part1", &self.part1), ("part2", &self.part2)]
+    }
+
+    pub fn to_json(&self) -> String {
+        let phases: Vec<String> = self.phases().iter()
+            .map(|(name, stats)| format!("\"{}\":{}", name, stats.to_json()))
+            .collect();
+        match &self.memory {
+            Some(memory) => {
+                let memory: Vec<String> = self.phases().iter().zip(memory)
+                    .map(|((name, _), m)| format!("\"{}\":{}", name, m.to_json()))
+                    .collect();
+                format!("{{\"day\":{},{},\"memory\":{{{}}}}}", self.day, phases.join(","), memory.join(","))
+            },
+            None => format!("{{\"day\":{},{}}}", self.day, phases.join(",")),
+        }
+    }
+}
+
+pub fn bench_day(day: &Day, inp: &[String], config: &BenchConfig) -> Result<DayBench, AocError> {
+    let solution = day.parse(inp.to_vec())?;
+    let memory = if config.memory {
+        let copy = inp.to_vec();
+        Some([
+            alloc::track(move || day.parse(copy)).1,
+            alloc::track(|| solution.part1()).1,
+            alloc::track(|| solution.part2()).1,
+        ])
+    } else {
+        None
+    };
+    Ok(DayBench {
+        day: day.day,
+        parse: measure(config, || inp.to_vec(), |i| day.parse(i)),
+        part1: measure(config, || (), |_| solution.part1()),
+        part2: measure(config, || (), |_| solution.part2()),
+        memory,
+    })
+}
+
+// Report of a whole run, tagged with the machine so numbers can be compared across hosts
+pub fn to_json(results: &[DayBench], config: &BenchConfig) -> String {
+    let threads = std::thread::available_parallelism().map(|n| n.get()).unwrap_or(1);
+    let days: Vec<String> = results.iter().map(|r| r.to_json()).collect();
+    format!(
+        "{{\"os\":\"{}\",\"arch\":\"{}\",\"threads\":{},\"warmup\":{},\"iterations\":{},\"results\":[{}]}}",
+        std::env::consts::OS, std::env::consts::ARCH, threads, config.warmup, config.iterations, days.join(",")
+    )
+}
+
+#[cfg(test)]
+mod bench {
+    use super::*;
+
+    #[test]
+    fn stats() {
+        let samples: Vec<Duration> = (1..=20).rev().map(Duration::from_micros).collect();
+        let stats = Stats::from_samples(samples);
+        assert_eq!(stats.samples, 20);
+        assert_eq!(stats.min, Duration::from_micros(1));
+        assert_eq!(stats.median, Duration::from_nanos(10500));
+        assert_eq!(stats.p95, Duration::from_micros(19));
+        assert_eq!(stats.mean, Duration::from_nanos(10500));
+
+        let config = BenchConfig { warmup: 2, iterations: 5, max_time: Duration::from_secs(60), memory: false };
+        let mut runs = 0;
+        let stats = measure(&config, || 1, |x| runs += x);
+        assert_eq!((stats.samples, runs), (5, 7));
+    }
+}
@@ -0,0 +1,113 @@
+use crate::utils::render::{Canvas, Export};
+use image::codecs::gif::{GifEncoder, Repeat};
+use image::{DynamicImage, Delay, Frame};
+use std::fs::{self, File};
+use std::io::{self, BufWriter};
+use std::path::PathBuf;
+use std::time::Duration;
+
+// Frame by frame recording of a simulation, saved as an animated GIF or as numbered PNGs
+
+// Keeps the state after every `every`th step. Frames are only drawn for steps that are kept.
+#[derive(Debug, Clone)]
+pub struct Recorder {
+    every: usize,
+    steps: usize,
+    frames: Vec<Canvas>,
+}
+
+impl Recorder {
+    pub fn new(every: usize) -> Recorder {
+        assert!(every > 0, "can't keep every 0th step");
+        Recorder { every, steps: 0, frames: vec![] }
+    }
+
+    // Always kept, e.g. the state before the first step
+    pub fn frame(&mut self, canvas: Canvas) {
+        self.frames.push(canvas);
+    }
+
+    // Call after each step of the simulation
+    pub fn step(&mut self, draw: impl FnOnce() -> Canvas) {
+        self.steps += 1;
+        if self.steps.is_multiple_of(self.every) {
+            self.frames.push(draw());
+        }
+    }
+
+    pub fn steps(&self) -> usize {
+        self.steps
+    }
+
+    pub fn into_frames(self) -> Vec<Canvas> {
+        self.frames
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FrameFormat {
+    // One looping `<name>.gif`
+    Gif,
+    // `<name>/00000.png`, `<name>/00001.png`, ...
+    Png,
+}
+
+impl Export {
+    // Save `frames`, shown `delay` apart in a GIF. Returns the files written.
+    pub fn save_frames(&self, frames: &[Canvas], name: &str, format: FrameFormat, delay: Duration) -> io::Result<Vec<PathBuf>> {
+        match format {
+            FrameFormat::Gif => {
+                fs::create_dir_all(&self.dir)?;
+                let path = self.dir.join(format!("{}.gif", name));
+                let mut encoder = GifEncoder::new_with_speed(BufWriter::new(File::create(&path)?), 10);
+                encoder.set_repeat(Repeat::Infinite).map_err(io::Error::other)?;
+                for canvas in frames {
+                    let rgba = DynamicImage::ImageRgb8(canvas.image(self.scale)).to_rgba8();
+                    encoder.encode_frame(Frame::from_parts(rgba, 0, 0, Delay::from_saturating_duration(delay))).map_err(io::Error::other)?;
+                }
+                Ok(vec![path])
+            },
+            FrameFormat::Png => {
+                let export = Export::new(self.dir.join(name), self.scale);
+                frames.iter().enumerate().map(|(k, canvas)| export.save(canvas, &format!("{:05}", k))).collect()
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod frames {
+    use super::*;
+    use crate::utils::render::{BLACK, WHITE};
+    use image::codecs::gif::GifDecoder;
+    use image::AnimationDecoder;
+    use std::env;
+
+    #[test]
+    fn recording() {
+        let mut recorder = Recorder::new(3);
+        recorder.frame(Canvas::new(2, 3, BLACK));
+        for k in 0..10 {
+            recorder.step(|| {
+                let mut canvas = Canvas::new(2, 3, BLACK);
+                canvas.paint([(k % 2, k % 3)], WHITE);
+                canvas
+            });
+        }
+        assert_eq!(recorder.steps(), 10);
+        let frames = recorder.into_frames();
+        // The first frame, then steps 3, 6 and 9
+        assert_eq!(frames.len(), 4);
+        assert_eq!(frames[1].get((0, 2)), Some(WHITE));
+
+        let dir = env::temp_dir().join(format!("aoc-frames-{}", std::process::id()));
+        let export = Export::new(dir.clone(), 2);
+        let gif = export.save_frames(&frames, "walk", FrameFormat::Gif, Duration::from_millis(50)).unwrap();
+        let decoded = GifDecoder::new(File::open(&gif[0]).unwrap()).unwrap().into_frames().collect_frames().unwrap();
+        assert_eq!(decoded.len(), 4);
+        assert_eq!(decoded[0].buffer().dimensions(), (6, 4));
+        let pngs = export.save_frames(&frames, "walk", FrameFormat::Png, Duration::ZERO).unwrap();
+        assert_eq!(pngs.last().unwrap(), &dir.join("walk").join("00003.png"));
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
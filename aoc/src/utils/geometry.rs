@@ -0,0 +1,252 @@
+use std::fmt::{self, Display, Formatter};
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+// Signed position on a map, `r` grows downwards and `c` to the right like `Grid` indices
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point {
+    pub r: isize,
+    pub c: isize,
+}
+
+impl Point {
+    pub const fn new(r: isize, c: isize) -> Point {
+        Point { r, c }
+    }
+
+    pub fn from_index((r, c): (usize, usize)) -> Point {
+        Point::new(r as isize, c as isize)
+    }
+
+    // `None` for points with a negative coordinate; use `Grid::index_of` to also check the far edges
+    pub fn to_index(self) -> Option<(usize, usize)> {
+        if self.r >= 0 && self.c >= 0 { Some((self.r as usize, self.c as usize)) } else { None }
+    }
+
+    pub fn manhattan(self, other: Point) -> usize {
+        self.r.abs_diff(other.r) + self.c.abs_diff(other.c)
+    }
+
+    pub fn neighbours4(self) -> impl Iterator<Item = Point> {
+        Dir::ORTHOGONAL.into_iter().map(move |d| self + d)
+    }
+
+    pub fn neighbours8(self) -> impl Iterator<Item = Point> {
+        Dir::ALL.into_iter().map(move |d| self + d)
+    }
+}
+
+impl From<(isize, isize)> for Point {
+    fn from((r, c): (isize, isize)) -> Point {
+        Point::new(r, c)
+    }
+}
+
+impl From<Point> for (isize, isize) {
+    fn from(p: Point) -> (isize, isize) {
+        (p.r, p.c)
+    }
+}
+
+impl Display for Point {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.r, self.c)
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, o: Point) -> Point {
+        Point::new(self.r + o.r, self.c + o.c)
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, o: Point) -> Point {
+        Point::new(self.r - o.r, self.c - o.c)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, o: Point) {
+        *self = *self + o;
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, o: Point) {
+        *self = *self - o;
+    }
+}
+
+impl Mul<isize> for Point {
+    type Output = Point;
+
+    fn mul(self, k: isize) -> Point {
+        Point::new(self.r * k, self.c * k)
+    }
+}
+
+impl Neg for Point {
+    type Output = Point;
+
+    fn neg(self) -> Point {
+        Point::new(-self.r, -self.c)
+    }
+}
+
+// One step in a direction
+impl Add<Dir> for Point {
+    type Output = Point;
+
+    fn add(self, d: Dir) -> Point {
+        self + d.delta()
+    }
+}
+
+impl AddAssign<Dir> for Point {
+    fn add_assign(&mut self, d: Dir) {
+        *self = *self + d;
+    }
+}
+
+impl Sub<Dir> for Point {
+    type Output = Point;
+
+    fn sub(self, d: Dir) -> Point {
+        self - d.delta()
+    }
+}
+
+// Compass directions, north is up (decreasing row)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Dir {
+    N,
+    NE,
+    E,
+    SE,
+    S,
+    SW,
+    W,
+    NW,
+}
+
+impl Dir {
+    // Clockwise from north
+    pub const ORTHOGONAL: [Dir; 4] = [Dir::N, Dir::E, Dir::S, Dir::W];
+    pub const ALL: [Dir; 8] = [Dir::N, Dir::NE, Dir::E, Dir::SE, Dir::S, Dir::SW, Dir::W, Dir::NW];
+
+    fn from_eighths(k: usize) -> Dir {
+        Dir::ALL[k % 8]
+    }
+
+    pub fn delta(self) -> Point {
+        match self {
+            Dir::N => Point::new(-1, 0),
+            Dir::NE => Point::new(-1, 1),
+            Dir::E => Point::new(0, 1),
+            Dir::SE => Point::new(1, 1),
+            Dir::S => Point::new(1, 0),
+            Dir::SW => Point::new(1, -1),
+            Dir::W => Point::new(0, -1),
+            Dir::NW => Point::new(-1, -1),
+        }
+    }
+
+    // 90 degrees clockwise
+    pub fn turn_right(self) -> Dir {
+        Dir::from_eighths(self as usize + 2)
+    }
+
+    // 90 degrees anticlockwise
+    pub fn turn_left(self) -> Dir {
+        Dir::from_eighths(self as usize + 6)
+    }
+
+    pub fn turn_right_45(self) -> Dir {
+        Dir::from_eighths(self as usize + 1)
+    }
+
+    pub fn turn_left_45(self) -> Dir {
+        Dir::from_eighths(self as usize + 7)
+    }
+
+    pub fn reverse(self) -> Dir {
+        Dir::from_eighths(self as usize + 4)
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        self as usize % 2 == 1
+    }
+
+    pub fn is_vertical(self) -> bool {
+        self == Dir::N || self == Dir::S
+    }
+
+    pub fn is_horizontal(self) -> bool {
+        self == Dir::E || self == Dir::W
+    }
+
+    // `^`, `v`, `<` and `>` as used by robot and guard maps
+    pub fn from_arrow(c: char) -> Option<Dir> {
+        match c {
+            '^' => Some(Dir::N),
+            'v' => Some(Dir::S),
+            '<' => Some(Dir::W),
+            '>' => Some(Dir::E),
+            _ => None,
+        }
+    }
+
+    // Inverse of `from_arrow`, `None` for diagonals
+    pub fn to_arrow(self) -> Option<char> {
+        match self {
+            Dir::N => Some('^'),
+            Dir::S => Some('v'),
+            Dir::W => Some('<'),
+            Dir::E => Some('>'),
+            _ => None,
+        }
+    }
+
+    // `N`, `S`, `E`, `W`, also accepting `U`, `D`, `L`, `R`
+    pub fn from_letter(c: char) -> Option<Dir> {
+        match c.to_ascii_uppercase() {
+            'N' | 'U' => Some(Dir::N),
+            'S' | 'D' => Some(Dir::S),
+            'E' | 'R' => Some(Dir::E),
+            'W' | 'L' => Some(Dir::W),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod geometry {
+    use super::*;
+
+    #[test]
+    fn turns() {
+        assert_eq!(Dir::N.turn_right(), Dir::E);
+        assert_eq!(Dir::W.turn_right(), Dir::N);
+        assert_eq!(Dir::N.turn_left(), Dir::W);
+        assert_eq!(Dir::SE.reverse(), Dir::NW);
+        assert_eq!(Dir::NW.turn_right_45(), Dir::N);
+        assert!(Dir::ALL.iter().all(|d| d.turn_left().turn_right() == *d && d.delta() == -d.reverse().delta()));
+        assert_eq!("^v<>".chars().map(|c| Dir::from_arrow(c).unwrap()).collect::<Vec<_>>(), vec![Dir::N, Dir::S, Dir::W, Dir::E]);
+        assert_eq!(Dir::from_letter('w'), Some(Dir::W));
+        assert!(Dir::ORTHOGONAL.iter().all(|&d| Dir::from_arrow(d.to_arrow().unwrap()) == Some(d)));
+        assert_eq!(Dir::NE.to_arrow(), None);
+
+        let p = Point::new(2, 3);
+        assert_eq!(p + Dir::N, Point::new(1, 3));
+        assert_eq!((p - Point::new(1, 1)) * 2, Point::new(2, 4));
+        assert_eq!(p.manhattan(Point::new(-1, 5)), 5);
+        assert_eq!(Point::new(0, 0).neighbours8().filter(|n| n.to_index().is_some()).count(), 3);
+        assert_eq!(Point::new(0, -1).to_index(), None);
+        assert_eq!(Point::from_index((4, 5)).to_index(), Some((4, 5)));
+    }
+}
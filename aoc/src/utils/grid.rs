@@ -0,0 +1,228 @@
+use crate::error::AocError;
+use crate::utils::geometry::Point;
+use std::fmt::{self, Debug, Display, Formatter};
+use std::ops::{Index, IndexMut};
+
+// Rectangular map stored row-major in one `Vec`, addressed by `(row, column)`
+
+// Up, right, down, left
+pub const ORTHOGONAL: [(isize, isize); 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+// Clockwise from up
+pub const ALL_DIRECTIONS: [(isize, isize); 8] = [(-1, 0), (-1, 1), (0, 1), (1, 1), (1, 0), (1, -1), (0, -1), (-1, -1)];
+
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    rows: usize,
+    cols: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(rows: usize, cols: usize, value: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid { rows, cols, cells: vec![value; rows * cols] }
+    }
+
+    pub fn from_vec(rows: usize, cols: usize, cells: Vec<T>) -> Grid<T> {
+        assert_eq!(cells.len(), rows * cols, "{} cells don't make a {}x{} grid", cells.len(), rows, cols);
+        Grid { rows, cols, cells }
+    }
+
+    // Map every character to a tile. `tile` gets the position, so it can also note where e.g. the start is.
+    // Fails on empty input or rows of different length.
+    pub fn parse<F>(inp: &[String], mut tile: F) -> Result<Grid<T>, AocError>
+    where
+        F: FnMut((usize, usize), char) -> Result<T, AocError>,
+    {
+        let cols = inp.first().ok_or(AocError::EmptyInput)?.chars().count();
+        let mut cells = Vec::with_capacity(inp.len() * cols);
+        for (i, line) in inp.iter().enumerate() {
+            if line.chars().count() != cols {
+                return Err(AocError::malformed(i, "row length differs from the first row"));
+            }
+            for (j, c) in line.chars().enumerate() {
+                cells.push(tile((i, j), c)?);
+            }
+        }
+        Ok(Grid { rows: inp.len(), cols, cells })
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn contains(&self, (r, c): (isize, isize)) -> bool {
+        r >= 0 && c >= 0 && (r as usize) < self.rows && (c as usize) < self.cols
+    }
+
+    pub fn get(&self, (r, c): (usize, usize)) -> Option<&T> {
+        if r < self.rows && c < self.cols { Some(&self.cells[r * self.cols + c]) } else { None }
+    }
+
+    pub fn get_mut(&mut self, (r, c): (usize, usize)) -> Option<&mut T> {
+        if r < self.rows && c < self.cols { Some(&mut self.cells[r * self.cols + c]) } else { None }
+    }
+
+    // Lookup by signed coordinates, `None` off the grid
+    pub fn at(&self, (r, c): (isize, isize)) -> Option<&T> {
+        if self.contains((r, c)) { self.get((r as usize, c as usize)) } else { None }
+    }
+
+    // Index of a point, `None` off the grid
+    pub fn index_of(&self, p: Point) -> Option<(usize, usize)> {
+        if self.contains(p.into()) { Some((p.r as usize, p.c as usize)) } else { None }
+    }
+
+    // `pos` moved by `delta`, if that is still on the grid
+    pub fn offset(&self, (r, c): (usize, usize), (dr, dc): (isize, isize)) -> Option<(usize, usize)> {
+        let next = (r as isize + dr, c as isize + dc);
+        if self.contains(next) { Some((next.0 as usize, next.1 as usize)) } else { None }
+    }
+
+    // Up, right, down and left neighbours that lie on the grid
+    pub fn neighbours4(&self, pos: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        ORTHOGONAL.iter().filter_map(move |&d| self.offset(pos, d))
+    }
+
+    // Neighbours including diagonals, clockwise from up
+    pub fn neighbours8(&self, pos: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        ALL_DIRECTIONS.iter().filter_map(move |&d| self.offset(pos, d))
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let cols = self.cols;
+        (0..self.rows * cols).map(move |k| (k / cols, k % cols))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn find(&self, value: &T) -> Option<(usize, usize)>
+    where
+        T: PartialEq,
+    {
+        self.cells.iter().position(|t| t == value).map(|k| (k / self.cols, k % self.cols))
+    }
+
+    pub fn find_all<'a>(&'a self, value: &'a T) -> impl Iterator<Item = (usize, usize)> + 'a
+    where
+        T: PartialEq,
+    {
+        self.iter().filter(move |(_, t)| *t == value).map(|(p, _)| p)
+    }
+
+    pub fn row(&self, r: usize) -> &[T] {
+        &self.cells[r * self.cols..(r + 1) * self.cols]
+    }
+
+    pub fn column(&self, c: usize) -> impl Iterator<Item = &T> {
+        assert!(c < self.cols, "column {} out of range for {} columns", c, self.cols);
+        self.cells.iter().skip(c).step_by(self.cols)
+    }
+
+    pub fn map<U, F: FnMut(&T) -> U>(&self, f: F) -> Grid<U> {
+        Grid { rows: self.rows, cols: self.cols, cells: self.cells.iter().map(f).collect() }
+    }
+}
+
+impl Grid<char> {
+    // The map as is, for days that look characters up with `find`
+    pub fn from_chars(inp: &[String]) -> Result<Grid<char>, AocError> {
+        Grid::parse(inp, |_, c| Ok(c))
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (r, c): (usize, usize)) -> &T {
+        assert!(r < self.rows && c < self.cols, "({}, {}) is outside the {}x{} grid", r, c, self.rows, self.cols);
+        &self.cells[r * self.cols + c]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (r, c): (usize, usize)) -> &mut T {
+        assert!(r < self.rows && c < self.cols, "({}, {}) is outside the {}x{} grid", r, c, self.rows, self.cols);
+        &mut self.cells[r * self.cols + c]
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, p: Point) -> &T {
+        let pos = self.index_of(p).unwrap_or_else(|| panic!("{} is outside the {}x{} grid", p, self.rows, self.cols));
+        &self[pos]
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, p: Point) -> &mut T {
+        let pos = self.index_of(p).unwrap_or_else(|| panic!("{} is outside the {}x{} grid", p, self.rows, self.cols));
+        &mut self[pos]
+    }
+}
+
+// One line per row
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for r in 0..self.rows {
+            for t in self.row(r) {
+                write!(f, "{}", t)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+// Same layout as `Display`, for tiles that only implement `Debug`
+impl<T: Debug> Debug for Grid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        writeln!(f)?;
+        for r in 0..self.rows {
+            for t in self.row(r) {
+                write!(f, "{:?}", t)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod grid {
+    use super::*;
+
+    #[test]
+    fn lookup() {
+        let inp: Vec<String> = vec!["S.#".to_owned(), "..E".to_owned()];
+        let grid = Grid::from_chars(&inp).unwrap();
+        assert_eq!((grid.rows(), grid.cols()), (2, 3));
+        assert_eq!(grid.find(&'E'), Some((1, 2)));
+        assert_eq!(grid[(0, 2)], '#');
+        assert_eq!(grid.at((-1, 0)), None);
+        assert_eq!(grid[Point::new(1, 2)], 'E');
+        assert_eq!(grid.index_of(Point::new(1, 3)), None);
+        assert_eq!(grid.neighbours4((0, 0)).collect::<Vec<_>>(), vec![(0, 1), (1, 0)]);
+        assert_eq!(grid.neighbours8((1, 1)).count(), 5);
+        assert_eq!(grid.row(1), &['.', '.', 'E']);
+        assert_eq!(grid.column(2).collect::<String>(), "#E");
+        assert_eq!(grid.to_string(), "S.#\n..E\n");
+
+        let walls = Grid::parse(&inp, |_, c| Ok(c == '#')).unwrap();
+        assert_eq!(walls.find_all(&true).collect::<Vec<_>>(), vec![(0, 2)]);
+        let ragged = Grid::from_chars(&["..".to_owned(), ".".to_owned()]).unwrap_err();
+        assert_eq!(ragged.to_string(), AocError::malformed(1, "row length differs from the first row").to_string());
+        let bad = Grid::parse(&inp, |(i, j), c| if c == 'E' { Err(AocError::unexpected_char(i, j, c)) } else { Ok(c) });
+        assert!(bad.is_err());
+    }
+}
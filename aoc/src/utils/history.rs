@@ -0,0 +1,261 @@
+use crate::utils::workspace_dir;
+use crate::utils::bench::DayBench;
+use std::collections::HashMap;
+use std::fmt::{self, Display, Formatter};
+use std::fs::{File, OpenOptions};
+use std::io::{self, BufRead, BufReader, Error, ErrorKind, Write};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+// Benchmark history is a tab separated file with one line per day and phase of a run:
+//
+//     <unix time> <commit> <year> <day> <phase> <median ns> <min ns> <p95 ns> <samples>
+//
+// Lines written before the year column was added have no year; they are all 2024's.
+const UNDATED_YEAR: u32 = 2024;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Record {
+    pub timestamp: u64,
+    pub commit: String,
+    pub year: u32,
+    pub day: u32,
+    pub phase: String,
+    pub median: Duration,
+    pub min: Duration,
+    pub p95: Duration,
+    pub samples: usize,
+}
+
+impl Record {
+    fn to_line(&self) -> String {
+        format!("{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}", self.timestamp, self.commit, self.year, self.day, self.phase,
+            self.median.as_nanos(), self.min.as_nanos(), self.p95.as_nanos(), self.samples)
+    }
+
+    fn from_line(line: &str) -> Option<Record> {
+        let mut f: Vec<&str> = line.split('\t').collect();
+        let year = match f.len() {
+            9 => f.remove(2).parse().ok()?,
+            8 => UNDATED_YEAR,
+            _ => return None,
+        };
+        let nanos = |s: &str| s.parse::<u64>().ok().map(Duration::from_nanos);
+        Some(Record {
+            timestamp: f[0].parse().ok()?,
+            commit: f[1].to_owned(),
+            year,
+            day: f[2].parse().ok()?,
+            phase: f[3].to_owned(),
+            median: nanos(f[4])?,
+            min: nanos(f[5])?,
+            p95: nanos(f[6])?,
+            samples: f[7].parse().ok()?,
+        })
+    }
+}
+
+pub fn default_path() -> PathBuf {
+    workspace_dir().join("bench_history.tsv")
+}
+
+// Short hash of HEAD, suffixed with `-dirty` when the tree has local changes
+pub fn git_commit() -> String {
+    let git = |args: &[&str]| Command::new("git")
+        .args(args)
+        .current_dir(workspace_dir())
+        .output()
+        .ok()
+        .filter(|o| o.status.success())
+        .map(|o| String::from_utf8_lossy(&o.stdout).trim().to_owned());
+    match git(&["rev-parse", "--short", "HEAD"]) {
+        Some(hash) => match git(&["status", "--porcelain", "--untracked-files=no"]) {
+            Some(status) if !status.is_empty() => hash + "-dirty",
+            _ => hash,
+        },
+        None => "unknown".to_owned(),
+    }
+}
+
+pub fn now() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0)
+}
+
+pub fn records(results: &[DayBench], year: u32, commit: &str, timestamp: u64) -> Vec<Record> {
+    results.iter()
+        .flat_map(|r| r.phases().into_iter().map(move |(phase, stats)| Record {
+            timestamp,
+            commit: commit.to_owned(),
+            year,
+            day: r.day,
+            phase: phase.to_owned(),
+            median: stats.median,
+            min: stats.min,
+            p95: stats.p95,
+            samples: stats.samples,
+        }))
+        .collect()
+}
+
+pub fn append(path: &Path, records: &[Record]) -> Result<(), Error> {
+    let mut f = OpenOptions::new().create(true).append(true).open(path)?;
+    for r in records {
+        writeln!(f, "{}", r.to_line())?;
+    }
+    Ok(())
+}
+
+// A missing history file is an empty history
+pub fn load(path: &Path) -> Result<Vec<Record>, Error> {
+    let f = match File::open(path) {
+        Ok(f) => f,
+        Err(e) if e.kind() == ErrorKind::NotFound => return Ok(vec![]),
+        Err(e) => return Err(e),
+    };
+    let mut history = vec![];
+    for (i, line) in BufReader::new(f).lines().enumerate() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let r = Record::from_line(&line)
+            .ok_or_else(|| Error::new(ErrorKind::InvalidData, format!("{}: line {}: malformed record", path.display(), i + 1)))?;
+        history.push(r);
+    }
+    Ok(history)
+}
+
+// Latest record of every year, day and phase, optionally restricted to one commit
+pub fn baseline(history: &[Record], commit: Option<&str>) -> HashMap<(u32, u32, String), Record> {
+    let mut latest: HashMap<(u32, u32, String), Record> = HashMap::new();
+    for r in history.iter().filter(|r| commit.is_none_or(|c| r.commit == c)) {
+        let key = (r.year, r.day, r.phase.clone());
+        if latest.get(&key).is_none_or(|l| l.timestamp <= r.timestamp) {
+            latest.insert(key, r.clone());
+        }
+    }
+    latest
+}
+
+// Time allowed for a phase; `*` matches any day or phase
+#[derive(Debug, Clone, PartialEq)]
+pub struct Budget {
+    pub day: Option<u32>,
+    pub phase: Option<String>,
+    pub limit: Duration,
+}
+
+impl Budget {
+    fn applies(&self, day: u32, phase: &str) -> bool {
+        self.day.is_none_or(|d| d == day) && self.phase.as_deref().is_none_or(|p| p == phase)
+    }
+}
+
+// Budget file lines are `<day|*> <phase|*> <milliseconds>`, e.g. `16 part2 500`
+pub fn read_budgets(path: &Path) -> Result<Vec<Budget>, Error> {
+    let f = File::open(path).map_err(|e| Error::new(e.kind(), format!("{}: {}", path.display(), e)))?;
+    let mut budgets = vec![];
+    for (i, line) in BufReader::new(f).lines().enumerate() {
+        let line = line?;
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        if fields.is_empty() || fields[0].starts_with('#') {
+            continue;
+        }
+        let invalid = || Error::new(ErrorKind::InvalidData, format!("{}: line {}: expected `<day|*> <phase|*> <ms>`", path.display(), i + 1));
+        if fields.len() != 3 {
+            return Err(invalid());
+        }
+        budgets.push(Budget {
+            day: match fields[0] {
+                "*" => None,
+                d => Some(d.parse().map_err(|_| invalid())?),
+            },
+            phase: match fields[1] {
+                "*" => None,
+                p => Some(p.to_owned()),
+            },
+            limit: Duration::from_secs_f64(fields[2].parse::<f64>().map_err(|_| invalid())? / 1000.0),
+        });
+    }
+    Ok(budgets)
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Flag {
+    Slower { day: u32, phase: String, baseline: Record, current: Duration },
+    OverBudget { day: u32, phase: String, budget: Duration, current: Duration },
+}
+
+impl Display for Flag {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Flag::Slower { day, phase, baseline, current } => {
+                let change = 100.0 * (current.as_secs_f64() / baseline.median.as_secs_f64() - 1.0);
+                write!(f, "slower\tday {} {}: {:?} -> {:?} (+{:.1}% vs {})", day, phase, baseline.median, current, change, baseline.commit)
+            },
+            Flag::OverBudget { day, phase, budget, current } =>
+                write!(f, "over budget\tday {} {}: {:?} > {:?}", day, phase, current, budget),
+        }
+    }
+}
+
+// Flag phases of `year`'s days whose median grew by more than `threshold` (0.1 = 10%) or exceeds
+// a budget
+pub fn compare(results: &[DayBench], year: u32, baseline: &HashMap<(u32, u32, String), Record>, threshold: f64, budgets: &[Budget]) -> Vec<Flag> {
+    let mut flags = vec![];
+    for r in results {
+        for (phase, stats) in r.phases() {
+            if let Some(b) = baseline.get(&(year, r.day, phase.to_owned())) {
+                if stats.median.as_secs_f64() > b.median.as_secs_f64() * (1.0 + threshold) {
+                    flags.push(Flag::Slower { day: r.day, phase: phase.to_owned(), baseline: b.clone(), current: stats.median });
+                }
+            }
+            let limit = budgets.iter().filter(|b| b.applies(r.day, phase)).map(|b| b.limit).min();
+            if let Some(limit) = limit {
+                if stats.median > limit {
+                    flags.push(Flag::OverBudget { day: r.day, phase: phase.to_owned(), budget: limit, current: stats.median });
+                }
+            }
+        }
+    }
+    flags
+}
+
+#[cfg(test)]
+mod history {
+    use super::*;
+    use crate::utils::bench::Stats;
+
+    fn stats(micros: u64) -> Stats {
+        Stats::from_samples(vec![Duration::from_micros(micros)])
+    }
+
+    #[test]
+    fn regressions() {
+        let path = std::env::temp_dir().join(format!("aoc2024-history-{}.tsv", std::process::id()));
+        let old = vec![DayBench { day: 16, parse: stats(100), part1: stats(1000), part2: stats(1000), memory: None }];
+        append(&path, &records(&old, 2024, "abc1234", 1)).unwrap();
+        let history = load(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(history, records(&old, 2024, "abc1234", 1));
+
+        let new = vec![DayBench { day: 16, parse: stats(105), part1: stats(1000), part2: stats(5000), memory: None }];
+        let budgets = vec![Budget { day: Some(16), phase: None, limit: Duration::from_millis(2) }];
+        let flags = compare(&new, 2024, &baseline(&history, None), 0.1, &budgets);
+        assert_eq!(flags.len(), 2);
+        assert!(matches!(&flags[0], Flag::Slower { day: 16, phase, .. } if phase == "part2"));
+        assert!(matches!(&flags[1], Flag::OverBudget { day: 16, phase, .. } if phase == "part2"));
+        assert!(baseline(&history, Some("def5678")).is_empty());
+        // Another year's day 16 has no baseline yet
+        assert_eq!(compare(&new, 2025, &baseline(&history, None), 0.1, &[]), vec![]);
+    }
+
+    #[test]
+    fn undated_lines() {
+        let r = Record::from_line("1\tabc1234\t16\tpart2\t1000\t900\t1100\t10").unwrap();
+        assert_eq!((r.year, r.day, r.phase.as_str()), (2024, 16, "part2"));
+        assert_eq!(Record::from_line(&r.to_line()), Some(r));
+        assert_eq!(Record::from_line("1\tabc1234\tday\t16\tpart2\t1000\t900\t1100\t10"), None);
+    }
+}
@@ -0,0 +1,124 @@
+use std::env;
+use std::fmt::{self, Display, Formatter};
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Error, ErrorKind, Read};
+use std::path::{Path, PathBuf};
+use crate::Year;
+
+pub mod bench;
+pub mod alloc;
+pub mod history;
+pub mod grid;
+pub mod geometry;
+pub mod search;
+pub mod parse;
+pub mod rng;
+pub mod prop;
+pub mod render;
+pub mod frames;
+pub use self::bench::bench;
+
+// Environment variable naming a directory with a folder per year laid out like the year's `src/`
+// (`2024/aoc1/input`, ...). Files found there take precedence, so inputs need not be committed
+// to the repo.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum InputSource {
+    File(PathBuf),
+    Stdin,
+    Text(String),
+}
+
+impl InputSource {
+    pub fn read(&self) -> Result<Vec<String>, Error> {
+        match self {
+            InputSource::File(path) => {
+                let f = File::open(path).map_err(|e| Error::new(e.kind(), format!("{}: {}", path.display(), e)))?;
+                read_lines(BufReader::new(f))
+            },
+            InputSource::Stdin => read_lines(io::stdin().lock()),
+            InputSource::Text(text) => read_lines(text.as_bytes()),
+        }
+    }
+}
+
+impl Display for InputSource {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            InputSource::File(path) => write!(f, "{}", path.display()),
+            InputSource::Stdin => write!(f, "<stdin>"),
+            InputSource::Text(_) => write!(f, "<text>"),
+        }
+    }
+}
+
+// Lines without their `\n` or `\r\n` ending
+fn read_lines<R: BufRead>(r: R) -> Result<Vec<String>, Error> {
+    r.lines()
+        .map(|l| l.map(|mut v| {
+            if v.ends_with('\r') {
+                v.pop();
+            }
+            v
+        }))
+        .collect()
+}
+
+// The root of the workspace, which holds `target` and the benchmark history
+pub fn workspace_dir() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap()
+}
+
+// The year's folder in $AOC_INPUT_DIR
+pub fn input_dir(year: &Year) -> Option<PathBuf> {
+    env::var_os(INPUT_DIR_VAR).map(|d| PathBuf::from(d).join(year.year.to_string()))
+}
+
+// Directories that may hold files of a day, in lookup order
+pub fn day_dirs(year: &Year, day: u32) -> Vec<PathBuf> {
+    let dir = format!("aoc{}", day);
+    input_dir(year).into_iter()
+        .chain(Some(PathBuf::from(year.dir)))
+        .map(|d| d.join(&dir))
+        .collect()
+}
+
+pub fn day_file(year: &Year, day: u32, name: &str) -> PathBuf {
+    let dirs = day_dirs(year, day);
+    dirs.iter()
+        .map(|d| d.join(name))
+        .find(|p| p.is_file())
+        .unwrap_or_else(|| dirs[dirs.len() - 1].join(name))
+}
+
+pub fn read_input(filename: &str) -> Result<Vec<String>, Error> {
+    InputSource::File(PathBuf::from(filename)).read()
+}
+
+// File `name` (e.g. "input", "example2") of a day
+pub fn read_day(year: &Year, day: u32, name: &str) -> Result<Vec<String>, Error> {
+    InputSource::File(day_file(year, day, name)).read()
+}
+
+#[cfg(test)]
+mod utils {
+    use super::*;
+
+    #[test]
+    fn sources() {
+        let text = InputSource::Text("3   4\r\n4   3\n".to_owned()).read().unwrap();
+        assert_eq!(text, vec!["3   4", "4   3"]);
+        assert_eq!(read_lines("3   4\r\n\r\n4\r".as_bytes()).unwrap(), vec!["3   4", "", "4"]);
+        let dir = env::temp_dir().join(format!("aoc-utils-{}", std::process::id()));
+        std::fs::create_dir_all(dir.join("aoc1")).unwrap();
+        std::fs::write(dir.join("aoc1/example"), "1\n2\n").unwrap();
+        let year = Year { year: 2024, dir: Box::leak(dir.to_string_lossy().into_owned().into_boxed_str()), days: &[] };
+        assert_eq!(day_file(&year, 1, "example"), dir.join("aoc1/example"));
+        assert_eq!(read_day(&year, 1, "example").unwrap(), vec!["1", "2"]);
+        std::fs::remove_dir_all(&dir).unwrap();
+        let err = read_input("src/aoc1/missing").unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::NotFound);
+        assert!(err.to_string().starts_with("src/aoc1/missing: "));
+    }
+}
@@ -0,0 +1,131 @@
+use crate::error::{AocError, parse_token};
+use std::str::FromStr;
+
+// Helpers for the usual shapes of puzzle input. Line indices are 0-based positions in the whole
+// input, as `AocError`'s constructors expect, so errors point at the right line.
+
+// Consecutive lines of the input, starting at line `start`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Section<'a> {
+    pub start: usize,
+    pub lines: &'a [String],
+}
+
+impl<'a> Section<'a> {
+    pub fn len(&self) -> usize {
+        self.lines.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.lines.is_empty()
+    }
+
+    // Line `k` of the section, or an error naming what a truncated section was missing
+    pub fn line(&self, k: usize, expected: &str) -> Result<&'a String, AocError> {
+        self.lines.get(k).ok_or_else(|| AocError::unexpected_end(self.start + k, expected))
+    }
+
+    // Lines paired with their index in the whole input
+    pub fn enumerate(&self) -> impl Iterator<Item = (usize, &'a String)> {
+        let start = self.start;
+        self.lines.iter().enumerate().map(move |(k, l)| (start + k, l))
+    }
+}
+
+// Runs of non-blank lines
+pub fn sections(inp: &[String]) -> Vec<Section<'_>> {
+    let mut sections = vec![];
+    let mut start = 0;
+    for i in 0..=inp.len() {
+        if i == inp.len() || inp[i].trim().is_empty() {
+            if i > start {
+                sections.push(Section { start, lines: &inp[start..i] });
+            }
+            start = i + 1;
+        }
+    }
+    sections
+}
+
+// The lines before and after the first blank line; `expected` describes the blank line
+// in the error for input that has none
+pub fn split_blank<'a>(inp: &'a [String], expected: &str) -> Result<(Section<'a>, Section<'a>), AocError> {
+    let k = inp.iter()
+        .position(|l| l.trim().is_empty())
+        .ok_or_else(|| AocError::unexpected_end(inp.len(), expected))?;
+    Ok((Section { start: 0, lines: &inp[..k] }, Section { start: k + 1, lines: &inp[k + 1..] }))
+}
+
+// Records of `size` lines, each followed by a blank line. The last one may come up short,
+// which `Section::line` reports.
+pub fn records(inp: &[String], size: usize) -> Vec<Section<'_>> {
+    (0..inp.len())
+        .step_by(size + 1)
+        .map(|start| Section { start, lines: &inp[start..(start + size).min(inp.len())] })
+        .collect()
+}
+
+// Every integer in line `i`, with an optional leading minus, e.g. `p=0,4 v=3,-3` gives 0, 4, 3, -3
+pub fn integers<T: FromStr>(i: usize, line: &str) -> Result<Vec<T>, AocError> {
+    let bytes = line.as_bytes();
+    let mut numbers = vec![];
+    let mut k = 0;
+    while k < bytes.len() {
+        let negative = bytes[k] == b'-' && bytes.get(k + 1).is_some_and(u8::is_ascii_digit);
+        if !(bytes[k].is_ascii_digit() || negative) {
+            k += 1;
+            continue;
+        }
+        let start = k;
+        k += 1;
+        while k < bytes.len() && bytes[k].is_ascii_digit() {
+            k += 1;
+        }
+        numbers.push(parse_token(i, line, &line[start..k])?);
+    }
+    Ok(numbers)
+}
+
+// Exactly `N` integers of line `i`; `expected` describes the line's format otherwise
+pub fn integers_n<T: FromStr, const N: usize>(i: usize, line: &str, expected: &str) -> Result<[T; N], AocError> {
+    integers(i, line)?
+        .try_into()
+        .map_err(|_| AocError::malformed(i, &format!("expected {}", expected)))
+}
+
+// `sep` separated values of line `i`, e.g. `75,47,61` or `47|53`
+pub fn list<T: FromStr>(i: usize, line: &str, sep: char) -> Result<Vec<T>, AocError> {
+    line.split(sep).map(|s| parse_token(i, line, s)).collect()
+}
+
+#[cfg(test)]
+mod parse {
+    use super::*;
+
+    fn lines(text: &str) -> Vec<String> {
+        text.lines().map(|l| l.to_owned()).collect()
+    }
+
+    #[test]
+    fn helpers() {
+        assert_eq!(integers::<i32>(0, "p=0,4 v=3,-3").unwrap(), vec![0, 4, 3, -3]);
+        assert_eq!(integers_n::<u64, 2>(0, "Prize: X=8400, Y=5400", "`X=a, Y=b`").unwrap(), [8400, 5400]);
+        assert_eq!(integers_n::<u64, 2>(4, "X=1", "`X=a, Y=b`").unwrap_err().to_string(), "line 5: expected `X=a, Y=b`");
+        assert_eq!(integers::<u8>(1, "a 300").unwrap_err(), AocError::InvalidNumber { line: 2, column: 3, token: "300".to_owned() });
+        assert_eq!(list::<u32>(0, "75, 47,61", ',').unwrap(), vec![75, 47, 61]);
+        assert_eq!(list::<u32>(0, "47|x", '|').unwrap_err(), AocError::InvalidNumber { line: 1, column: 4, token: "x".to_owned() });
+
+        let inp = lines("a\nb\n\n\nc\n\nd\ne");
+        let starts: Vec<(usize, usize)> = sections(&inp).iter().map(|s| (s.start, s.len())).collect();
+        assert_eq!(starts, vec![(0, 2), (4, 1), (6, 2)]);
+        let (first, rest) = split_blank(&inp, "a blank line").unwrap();
+        assert_eq!((first.len(), rest.start), (2, 3));
+        assert_eq!(split_blank(&inp[..2], "a blank line").unwrap_err(), AocError::unexpected_end(2, "a blank line"));
+
+        let inp = lines("A\nB\nC\n\nA\nB");
+        let recs = records(&inp, 3);
+        assert_eq!(recs.len(), 2);
+        assert_eq!(recs[1].enumerate().map(|(i, _)| i).collect::<Vec<_>>(), vec![4, 5]);
+        assert_eq!(recs[1].line(2, "C").unwrap_err(), AocError::UnexpectedEnd { line: 7, expected: "C".to_owned() });
+    }
+}
@@ -0,0 +1,44 @@
+use crate::utils::rng::Rng;
+use std::env;
+use std::panic::{self, RefUnwindSafe};
+
+// Property checks over generated inputs. Case `k` is generated from seed `k`; a failing case
+// panics with its seed and input, and `AOC_SEED=<seed>` reruns just that case.
+
+pub const SEED_VAR: &str = "AOC_SEED";
+
+pub fn seeds(cases: u64) -> Vec<u64> {
+    match env::var(SEED_VAR).ok().and_then(|s| s.parse().ok()) {
+        Some(seed) => vec![seed],
+        None => (0..cases).collect(),
+    }
+}
+
+// Run `property` on `cases` inputs from `generate`. Properties use the usual asserts.
+pub fn check<G, P>(cases: u64, generate: G, property: P)
+where
+    G: Fn(&mut Rng) -> Vec<String>,
+    P: Fn(Vec<String>) + RefUnwindSafe,
+{
+    for seed in seeds(cases) {
+        let inp = generate(&mut Rng::new(seed));
+        let copy = inp.clone();
+        if panic::catch_unwind(|| property(copy)).is_err() {
+            panic!("property failed for {}={}, input:\n{}", SEED_VAR, seed, inp.join("\n"));
+        }
+    }
+}
+
+#[cfg(test)]
+mod prop {
+    use super::*;
+
+    #[test]
+    fn reports_seed() {
+        check(20, |rng| vec![rng.below(100).to_string()], |inp| assert!(inp[0].parse::<usize>().unwrap() < 100));
+        let failure = panic::catch_unwind(|| check(20, |rng| vec![rng.below(10).to_string()], |inp| assert_ne!(inp[0], "3")));
+        let message = failure.unwrap_err().downcast::<String>().unwrap();
+        assert!(message.starts_with("property failed for AOC_SEED="), "{}", message);
+        assert!(message.ends_with("input:\n3"), "{}", message);
+    }
+}
@@ -0,0 +1,166 @@
+use crate::utils::grid::Grid;
+use crate::utils::workspace_dir;
+use image::{imageops, Rgb, RgbImage};
+use std::env;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+// Pictures of grids and point sets, drawn one cell at a time and saved as PNG with every cell
+// scaled up to a square of pixels
+
+// Directory pictures are saved in
+pub const IMAGE_DIR_VAR: &str = "AOC_IMAGE_DIR";
+
+pub type Colour = [u8; 3];
+
+pub const BLACK: Colour = [0, 0, 0];
+pub const WHITE: Colour = [255, 255, 255];
+pub const GREY: Colour = [96, 96, 96];
+pub const RED: Colour = [220, 40, 40];
+pub const GREEN: Colour = [40, 170, 70];
+pub const BLUE: Colour = [50, 90, 220];
+pub const ORANGE: Colour = [240, 150, 30];
+pub const BROWN: Colour = [150, 100, 50];
+
+// Colour of each tile value, `default` for values without one
+#[derive(Debug, Clone)]
+pub struct Palette<T> {
+    colours: Vec<(T, Colour)>,
+    default: Colour,
+}
+
+impl<T: PartialEq> Palette<T> {
+    pub fn new(default: Colour) -> Palette<T> {
+        Palette { colours: vec![], default }
+    }
+
+    pub fn with(mut self, value: T, colour: Colour) -> Palette<T> {
+        self.colours.push((value, colour));
+        self
+    }
+
+    pub fn colour(&self, value: &T) -> Colour {
+        self.colours.iter().find(|(v, _)| v == value).map_or(self.default, |&(_, c)| c)
+    }
+}
+
+// Colour number `k` of a sequence of well separated colours, for numbered things like regions
+pub fn distinct(k: usize) -> Colour {
+    // Golden ratio steps around the hue circle
+    let hue = (k as f64 * 0.618_033_988_75).fract() * 6.0;
+    let (s, v) = (0.65, 0.95);
+    let x = v * s * (1.0 - (hue % 2.0 - 1.0).abs());
+    let low = v * (1.0 - s);
+    let (r, g, b) = match hue as usize {
+        0 => (v, x + low, low),
+        1 => (x + low, v, low),
+        2 => (low, v, x + low),
+        3 => (low, x + low, v),
+        4 => (x + low, low, v),
+        _ => (v, low, x + low),
+    };
+    [(r * 255.0) as u8, (g * 255.0) as u8, (b * 255.0) as u8]
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Canvas {
+    cells: Grid<Colour>,
+}
+
+impl Canvas {
+    pub fn new(rows: usize, cols: usize, background: Colour) -> Canvas {
+        Canvas { cells: Grid::new(rows, cols, background) }
+    }
+
+    pub fn from_grid<T: PartialEq>(grid: &Grid<T>, palette: &Palette<T>) -> Canvas {
+        Canvas { cells: grid.map(|t| palette.colour(t)) }
+    }
+
+    // For colours that depend on more than the tile, e.g. `distinct` region numbers
+    pub fn from_fn<T>(grid: &Grid<T>, colour: impl Fn(&T) -> Colour) -> Canvas {
+        Canvas { cells: grid.map(colour) }
+    }
+
+    pub fn rows(&self) -> usize {
+        self.cells.rows()
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cells.cols()
+    }
+
+    pub fn get(&self, pos: (usize, usize)) -> Option<Colour> {
+        self.cells.get(pos).copied()
+    }
+
+    // Colour `cells` on top of what is there, skipping any that lie off the canvas
+    pub fn paint(&mut self, cells: impl IntoIterator<Item = (usize, usize)>, colour: Colour) {
+        for pos in cells {
+            if let Some(c) = self.cells.get_mut(pos) {
+                *c = colour;
+            }
+        }
+    }
+
+    // Each cell as a `scale` x `scale` square
+    pub fn image(&self, scale: u32) -> RgbImage {
+        let small = RgbImage::from_fn(self.cols() as u32, self.rows() as u32, |x, y| Rgb(self.cells[(y as usize, x as usize)]));
+        imageops::resize(&small, small.width() * scale, small.height() * scale, imageops::FilterType::Nearest)
+    }
+}
+
+// Where and how large pictures are saved
+#[derive(Debug, Clone, PartialEq)]
+pub struct Export {
+    pub dir: PathBuf,
+    // Pixels per cell
+    pub scale: u32,
+}
+
+impl Export {
+    pub fn new(dir: PathBuf, scale: u32) -> Export {
+        Export { dir, scale }
+    }
+
+    // $AOC_IMAGE_DIR, falling back to `target/images` in the workspace, at 4 pixels per cell
+    pub fn from_env() -> Export {
+        let dir = env::var_os(IMAGE_DIR_VAR)
+            .map(PathBuf::from)
+            .unwrap_or_else(|| workspace_dir().join("target/images"));
+        Export::new(dir, 4)
+    }
+
+    // Save as `<dir>/<name>.png`, creating the directory if needed
+    pub fn save(&self, canvas: &Canvas, name: &str) -> io::Result<PathBuf> {
+        fs::create_dir_all(&self.dir)?;
+        let path = self.dir.join(format!("{}.png", name));
+        canvas.image(self.scale).save(&path).map_err(io::Error::other)?;
+        Ok(path)
+    }
+}
+
+#[cfg(test)]
+mod render {
+    use super::*;
+
+    #[test]
+    fn canvas() {
+        let grid = Grid::from_chars(&["#.".to_owned(), ".#".to_owned()]).unwrap();
+        let mut canvas = Canvas::from_grid(&grid, &Palette::new(WHITE).with('#', GREY));
+        canvas.paint([(0, 1), (5, 5)], RED);
+        assert_eq!((canvas.rows(), canvas.cols()), (2, 2));
+        assert_eq!([canvas.get((0, 0)), canvas.get((0, 1)), canvas.get((1, 0))], [Some(GREY), Some(RED), Some(WHITE)]);
+
+        let image = canvas.image(3);
+        assert_eq!(image.dimensions(), (6, 6));
+        assert_eq!(image.get_pixel(5, 0).0, RED);
+        assert_eq!(image.get_pixel(0, 5).0, WHITE);
+        assert_ne!(distinct(0), distinct(1));
+
+        let dir = env::temp_dir().join(format!("aoc-render-{}", std::process::id()));
+        let path = Export::new(dir.clone(), 2).save(&canvas, "test").unwrap();
+        assert_eq!(image::open(&path).unwrap().to_rgb8().dimensions(), (4, 4));
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
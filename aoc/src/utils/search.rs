@@ -0,0 +1,220 @@
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+
+// Shortest paths over implicit graphs. `neighbours` maps a state to the states reachable from it
+// (with the cost of the edge for Dijkstra and A*). The search stops once the first state accepted
+// by `goal` is settled and every other state as cheap as it has been expanded, so ties still get
+// all their predecessors; pass `|_| false` to explore everything reachable.
+
+#[derive(Debug, Clone)]
+pub struct Search<S> {
+    // Cost of the cheapest path from any start
+    pub dist: HashMap<S, usize>,
+    // Every state that precedes a state on one of its cheapest paths
+    pub preds: HashMap<S, Vec<S>>,
+    // First goal state reached
+    pub goal: Option<S>,
+}
+
+impl<S: Clone + Eq + Hash> Search<S> {
+    fn new() -> Search<S> {
+        Search { dist: HashMap::new(), preds: HashMap::new(), goal: None }
+    }
+
+    pub fn cost(&self, s: &S) -> Option<usize> {
+        self.dist.get(s).copied()
+    }
+
+    pub fn goal_cost(&self) -> Option<usize> {
+        self.goal.as_ref().and_then(|g| self.cost(g))
+    }
+
+    // Record that `from` reaches `to` at cost `d`; true if that is a new best
+    fn relax(&mut self, from: &S, to: S, d: usize) -> bool {
+        match self.dist.get(&to) {
+            Some(&old) if d > old => false,
+            Some(&old) if d == old => {
+                self.preds.entry(to).or_default().push(from.clone());
+                false
+            },
+            _ => {
+                self.dist.insert(to.clone(), d);
+                self.preds.insert(to, vec![from.clone()]);
+                true
+            },
+        }
+    }
+
+    // One cheapest path from a start to `to`, both ends included
+    pub fn path(&self, to: &S) -> Option<Vec<S>> {
+        self.dist.get(to)?;
+        let mut path = vec![to.clone()];
+        while let Some(prev) = self.preds.get(path.last().unwrap()).and_then(|p| p.first()) {
+            path.push(prev.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    // Every state on some cheapest path to any of `targets`
+    pub fn on_optimal_paths<'a>(&self, targets: impl IntoIterator<Item = &'a S>) -> HashSet<S>
+    where
+        S: 'a,
+    {
+        let mut seen = HashSet::new();
+        let mut stack: Vec<S> = targets.into_iter().filter(|t| self.dist.contains_key(t)).cloned().collect();
+        while let Some(s) = stack.pop() {
+            if !seen.insert(s.clone()) {
+                continue;
+            }
+            stack.extend(self.preds.get(&s).into_iter().flatten().cloned());
+        }
+        seen
+    }
+}
+
+// Unit cost edges
+pub fn bfs<S, N, I, G>(starts: impl IntoIterator<Item = S>, mut neighbours: N, mut goal: G) -> Search<S>
+where
+    S: Clone + Eq + Hash,
+    N: FnMut(&S) -> I,
+    I: IntoIterator<Item = S>,
+    G: FnMut(&S) -> bool,
+{
+    let mut search = Search::new();
+    let mut queue = VecDeque::new();
+    for s in starts {
+        if !search.dist.contains_key(&s) {
+            search.dist.insert(s.clone(), 0);
+            queue.push_back(s);
+        }
+    }
+    while let Some(s) = queue.pop_front() {
+        let d = search.dist[&s];
+        if search.goal_cost().is_some_and(|g| d > g) {
+            break;
+        }
+        if search.goal.is_none() && goal(&s) {
+            search.goal = Some(s.clone());
+        }
+        for n in neighbours(&s) {
+            if search.relax(&s, n.clone(), d + 1) {
+                queue.push_back(n);
+            }
+        }
+    }
+    search
+}
+
+pub fn dijkstra<S, N, I, G>(starts: impl IntoIterator<Item = S>, neighbours: N, goal: G) -> Search<S>
+where
+    S: Clone + Eq + Hash,
+    N: FnMut(&S) -> I,
+    I: IntoIterator<Item = (S, usize)>,
+    G: FnMut(&S) -> bool,
+{
+    astar(starts, neighbours, |_| 0, goal)
+}
+
+// `heuristic` must never overestimate the remaining cost, nor drop by more than an edge costs
+pub fn astar<S, N, I, H, G>(starts: impl IntoIterator<Item = S>, mut neighbours: N, mut heuristic: H, mut goal: G) -> Search<S>
+where
+    S: Clone + Eq + Hash,
+    N: FnMut(&S) -> I,
+    I: IntoIterator<Item = (S, usize)>,
+    H: FnMut(&S) -> usize,
+    G: FnMut(&S) -> bool,
+{
+    let mut search = Search::new();
+    let mut heap = BinaryHeap::new();
+    let mut seq = 0;
+    for s in starts {
+        if !search.dist.contains_key(&s) {
+            search.dist.insert(s.clone(), 0);
+            heap.push(Entry { priority: heuristic(&s), cost: 0, seq, state: s });
+            seq += 1;
+        }
+    }
+    let mut settled = HashSet::new();
+    while let Some(Entry { priority, cost, state, .. }) = heap.pop() {
+        if cost > search.dist[&state] || !settled.insert(state.clone()) {
+            continue;
+        }
+        if search.goal_cost().is_some_and(|g| priority > g) {
+            break;
+        }
+        if search.goal.is_none() && goal(&state) {
+            search.goal = Some(state.clone());
+        }
+        for (n, c) in neighbours(&state) {
+            let d = cost + c;
+            if search.relax(&state, n.clone(), d) {
+                heap.push(Entry { priority: d + heuristic(&n), cost: d, seq, state: n });
+                seq += 1;
+            }
+        }
+    }
+    search
+}
+
+// Min-heap entry, ties broken by insertion order
+struct Entry<S> {
+    priority: usize,
+    cost: usize,
+    seq: usize,
+    state: S,
+}
+
+impl<S> PartialEq for Entry<S> {
+    fn eq(&self, other: &Self) -> bool {
+        (self.priority, self.seq) == (other.priority, other.seq)
+    }
+}
+
+impl<S> Eq for Entry<S> {}
+
+impl<S> Ord for Entry<S> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (other.priority, other.seq).cmp(&(self.priority, self.seq))
+    }
+}
+
+impl<S> PartialOrd for Entry<S> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[cfg(test)]
+mod search {
+    use super::*;
+
+    #[test]
+    fn shortest_paths() {
+        // Two equally cheap ways from 0 to 3 and a cheaper-looking dead end
+        let edges = |s: &u32| -> Vec<(u32, usize)> {
+            match s {
+                0 => vec![(1, 1), (2, 2), (4, 1)],
+                1 => vec![(3, 2)],
+                2 => vec![(3, 1)],
+                _ => vec![],
+            }
+        };
+        let search = dijkstra([0], edges, |s| *s == 3);
+        assert_eq!(search.goal_cost(), Some(3));
+        assert_eq!(search.preds[&3], vec![1, 2]);
+        assert_eq!(search.path(&3).unwrap().len(), 3);
+        let mut tied: Vec<u32> = search.on_optimal_paths([&3]).into_iter().collect();
+        tied.sort();
+        assert_eq!(tied, vec![0, 1, 2, 3]);
+
+        let search = astar([0], edges, |s| if *s == 4 { 10 } else { 0 }, |s| *s == 3);
+        assert_eq!(search.goal_cost(), Some(3));
+
+        let line = bfs([0i32], |s| vec![s - 1, s + 1], |s| *s == 5);
+        assert_eq!(line.goal_cost(), Some(5));
+        assert_eq!(line.path(&5).unwrap(), vec![0, 1, 2, 3, 4, 5]);
+        assert!(line.cost(&-7).is_none());
+    }
+}
@@ -0,0 +1,130 @@
+use crate::read_day;
+use aoc::utils::bench;
+use aoc::utils::prop;
+use aoc::utils::rng::Rng;
+use aoc::Answer;
+use aoc::error::{AocError, parse_token};
+use std::collections::HashMap;
+
+#[derive(Debug)]
+pub struct Context {
+    list1: Vec<i32>,
+    list2: Vec<i32>
+}
+
+impl Context {
+    pub fn new(inp: Vec<String>) -> Result<Context, AocError> {
+        let tmp: Vec<(i32, i32)> = inp.iter()
+        .enumerate()
+        .map(|(i, s)| { 
+            let mut nums = s.as_str().trim().split_whitespace();
+            let first = parse_token::<i32>(i, s, nums.next().ok_or(AocError::malformed(i, "expected two numbers"))?)?;
+            let second = parse_token::<i32>(i, s, nums.next().ok_or(AocError::malformed(i, "expected two numbers"))?)?;
+            Ok((first, second))
+        })
+        .collect::<Result<_, AocError>>()?;
+        
+        Ok(Context {
+            list1: {
+                let mut t: Vec<i32> = tmp.iter().map(|nums| nums.0).collect();
+                t.sort();
+                t
+            },
+            list2: {
+                let mut t: Vec<i32> = tmp.iter().map(|nums| nums.1).collect();
+                t.sort();
+                t
+            },
+        })
+    }
+
+    pub fn part1(&self) -> u32 {
+        let mut diff: u32 = 0;
+        for i in 0..self.list1.len() {
+            diff += (self.list1[i] - self.list2[i]).wrapping_abs() as u32;
+        }
+        diff
+    }
+
+    pub fn part2(&self) -> u32 {
+        let mut map: HashMap<i32, u32> = HashMap::new();
+        let mut sim: u32 = 0;
+        for i in 0..self.list2.len() {
+            map.entry(self.list2[i]).and_modify(|c| *c += 1).or_insert(1);
+        }
+        for i in 0..self.list1.len() {
+            sim += match map.get(&self.list1[i]) {
+                Some(x) => x * self.list1[i] as u32,
+                None => 0
+            };
+        }
+        sim
+    }
+}
+
+// Two columns of location IDs, with some IDs shared between the lists
+pub fn generate(rng: &mut Rng) -> Vec<String> {
+    let n = rng.range(1, 40) as usize;
+    let left: Vec<i64> = (0..n).map(|_| rng.range(1, 99999)).collect();
+    (0..n)
+        .map(|k| {
+            let right = if rng.chance(0.3) { *rng.pick(&left) } else { rng.range(1, 99999) };
+            format!("{}   {}", left[k], right)
+        })
+        .collect()
+}
+
+impl aoc::Solution for Context {
+    fn parse(inp: Vec<String>) -> Result<Self, AocError> {
+        Context::new(inp)
+    }
+
+    fn part1(&self) -> Answer {
+        Context::part1(self).into()
+    }
+
+    fn part2(&self) -> Answer {
+        Context::part2(self).into()
+    }
+}
+
+#[cfg(test)]
+mod aoc1 {
+    use super::*;
+
+    #[test]
+    fn properties() {
+        prop::check(50, generate, |inp| {
+            let swapped: Vec<String> = inp.iter()
+                .map(|l| l.split_whitespace().rev().collect::<Vec<&str>>().join("   "))
+                .collect();
+            let same: Vec<String> = inp.iter()
+                .map(|l| l.split_whitespace().next().unwrap())
+                .map(|id| format!("{}   {}", id, id))
+                .collect();
+            let context = Context::new(inp).unwrap();
+            // The distance doesn't depend on which list is which, and is zero between equal lists,
+            // where every ID also finds at least itself in the other list
+            assert_eq!(context.part1(), Context::new(swapped).unwrap().part1());
+            let same = Context::new(same).unwrap();
+            assert_eq!(same.part1(), 0);
+            assert!(same.part2() >= same.list1.iter().map(|&id| id as u32).sum());
+        });
+    }
+
+    #[test]
+    fn example1() {
+        let text: Vec<String> = read_day(1, "example").expect("couldn't read input - aoc1");
+        let context = Context::new(text).unwrap();
+        println!("Part1: {:?}", context.part1());
+        println!("Part2: {:?}", context.part2());
+    }
+
+    #[test]
+    fn actual() {
+        let text: Vec<String> = read_day(1, "input").expect("couldn't read input - aoc1");
+        let context = Context::new(text).unwrap();
+        println!("Part1: {:?}", bench(|| context.part1(), Some("aoc1: part1")));
+        println!("Part2: {:?}", bench(|| context.part2(), Some("aoc1: part2")));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,161 @@
+use crate::read_day;
+use aoc::utils::bench;
+use aoc::utils::prop;
+use aoc::utils::rng::Rng;
+use aoc::Answer;
+use aoc::error::AocError;
+use aoc::utils::grid::Grid;
+use aoc::utils::search::bfs;
+use std::fmt::{write, Debug, Formatter};
+use std::collections::{VecDeque, HashMap, HashSet};
+use std::hash::Hash;
+use rayon::prelude::*;
+
+type Height = i8;
+
+// First is the current coord, second is the origin
+
+#[derive(Debug)]
+struct QueueObj {
+    origin: (usize, usize),
+    cur: (usize, usize),
+    distance: usize,
+}
+
+impl QueueObj {
+    pub fn new(origin: (usize, usize), cur: (usize, usize), distance: usize) -> QueueObj {
+        QueueObj {
+            origin: origin,
+            cur: cur,
+            distance: distance,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Context {
+    heights: Grid<Height>,
+}
+
+impl Context {
+    pub fn new(inp: Vec<String>) -> Result<Context, AocError> {
+        Ok(Context {
+            heights: Grid::parse(&inp, |(i, j), c| c.to_digit(10).map(|d| d as Height).ok_or(AocError::unexpected_char(i, j, c)))?
+        })
+    }
+
+    fn uphill(&self, pos: (usize, usize)) -> Vec<(usize, usize)> {
+        self.heights.neighbours4(pos).filter(|&n| self.heights[n] == self.heights[pos] + 1).collect()
+    }
+
+    // Summit count of every trailhead
+    pub fn part1(&self) -> usize {
+        self.heights.find_all(&0)
+            .map(|start| {
+                let search = bfs([start], |&p| self.uphill(p), |_| false);
+                search.dist.keys().filter(|&&p| self.heights[p] == 9).count()
+            })
+            .sum()
+    }
+
+    pub fn part2(&self) -> usize {
+        let mut sum = 0;
+        let mut queue: VecDeque<QueueObj> = VecDeque::new();
+        for (i, j) in self.heights.find_all(&0) {
+            queue.push_back(QueueObj::new((i, j), (i, j), 0));
+        }
+        let mut visited: HashMap<(usize, usize), HashSet<(usize, usize)>> = HashMap::new();
+        //println!("Trailheads: {:?}", queue);
+        while !queue.is_empty() {
+            let node = queue.pop_front().unwrap();
+            let (iu, ju) = node.cur;
+            let cur_height = self.heights[(iu, ju)];
+            if cur_height == 9 {
+                sum += 1;
+                continue;
+            }
+            for (xu, yu) in self.heights.neighbours4((iu, ju)) {
+                if self.heights[(xu, yu)] == cur_height + 1 {
+                    queue.push_back(QueueObj::new(node.origin, (xu, yu), node.distance + 1));
+                }
+            }
+        }
+        //visited.values().map(|v| v.len()).sum()
+        sum
+    }
+
+}
+
+// Topographic map of random heights with a few hiking trails from 0 to 9 laid over it
+pub fn generate(rng: &mut Rng) -> Vec<String> {
+    let (rows, cols) = (rng.range(1, 10) as usize, rng.range(1, 10) as usize);
+    let mut heights = Grid::new(rows, cols, 0);
+    for pos in heights.positions().collect::<Vec<_>>() {
+        heights[pos] = rng.range(0, 9) as Height;
+    }
+    for _ in 0..rng.range(0, 5) {
+        let mut pos = (rng.below(rows), rng.below(cols));
+        heights[pos] = 0;
+        for h in 1..=9 {
+            let next: Vec<(usize, usize)> = heights.neighbours4(pos).collect();
+            if next.is_empty() {
+                break;
+            }
+            pos = *rng.pick(&next);
+            heights[pos] = h;
+        }
+    }
+    (0..rows).map(|r| heights.row(r).iter().map(|h| h.to_string()).collect()).collect()
+}
+
+impl aoc::Solution for Context {
+    fn parse(inp: Vec<String>) -> Result<Self, AocError> {
+        Context::new(inp)
+    }
+
+    fn part1(&self) -> Answer {
+        Context::part1(self).into()
+    }
+
+    fn part2(&self) -> Answer {
+        Context::part2(self).into()
+    }
+}
+
+#[allow(unused_imports)]
+#[cfg(test)]
+mod aoc10 {
+    use super::*;
+
+    #[test]
+    fn properties() {
+        prop::check(50, generate, |inp| {
+            let context = Context::new(inp).unwrap();
+            let heads = context.heights.find_all(&0).count();
+            let summits = context.heights.find_all(&9).count();
+            // Every reachable summit has at least one trail to it
+            assert!(context.part1() <= context.part2());
+            assert!(context.part1() <= heads * summits);
+            assert_eq!(context.part1() == 0, context.part2() == 0);
+        });
+    }
+
+    #[test]
+    fn example1() {
+        let text: Vec<String> = read_day(10, "example").expect("couldn't read input - aoc10");
+        let context = Context::new(text).unwrap();
+        //println!("context: {:?}", context);
+        let part1 = context.part1();
+        println!("Part1: {:?}", part1);
+        println!("Part2: {:?}", context.part2());
+    }
+
+    #[test]
+    fn actual() {
+        let text: Vec<String> = read_day(10, "input").expect("couldn't read input - aoc10");
+        let context = Context::new(text).unwrap();
+        let part1 = bench(|| context.part1(), Some("part1"));
+        println!("Part1: {:?}", part1);
+        println!("Part2: {:?}", bench(|| context.part2(), Some("part2")));
+    }
+}
\ No newline at end of file
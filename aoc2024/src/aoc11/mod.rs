@@ -0,0 +1,133 @@
+use crate::read_day;
+use aoc::utils::bench;
+use aoc::utils::prop;
+use aoc::utils::rng::Rng;
+use aoc::Answer;
+use aoc::error::{AocError, parse_token, get_line};
+use std::fmt::{write, Debug, Formatter};
+use std::collections::{VecDeque, HashMap, HashSet};
+use rayon::prelude::*;
+
+type Num = u64;
+
+#[derive(Debug)]
+pub struct Context {
+    nums: Vec<Num>,
+}
+
+impl Context {
+    pub fn new(inp: Vec<String>) -> Result<Context, AocError> {
+        let line = get_line(&inp, 0, "the stones")?;
+        Ok(Context {
+            nums: line
+                    .split_ascii_whitespace()
+                    .map(|v| parse_token::<Num>(0, line, v))
+                    .collect::<Result<_, AocError>>()?,
+        })
+    }
+
+    pub fn split_number(n: &Num) -> Vec<Num> {
+        let str_n = n.to_string();
+        let len = str_n.len();
+        let mid = len / 2;
+    
+        let left = str_n[..mid].parse::<Num>().unwrap();
+        let right = str_n[mid..].parse::<Num>().unwrap();
+    
+        vec![left, right]
+    }
+
+    pub fn process_number(num: &Num) -> Vec<Num> {
+        let num_digits = num.checked_ilog10().unwrap_or(0) + 1;
+        //println!("Num digits: {}", num_digits);
+        if *num == 0 {
+            return vec![1];
+        } else if num_digits % 2 == 0 {
+            //let split_nums = self.split_number(num);
+            //println!("Orig num: {}, Split nums: {:?}", num, split_nums);
+            return Context::split_number(num);
+        } else {
+            return vec![num * 2024];
+        }
+    }
+
+    pub fn part1(&self, blinks: usize) -> usize {
+        let mut map: HashMap<Num, usize> = HashMap::new();
+        for x in self.nums.iter() {
+            *map.entry(*x).or_insert(0) += 1;
+        }
+        for i in 0..blinks {
+            let mut new_map = HashMap::new();
+            for (s, n) in map.iter() {
+                for t in Context::process_number(s) {
+                    *new_map.entry(t).or_insert(0) += n;
+                }
+            }
+            map = new_map;
+        }
+        map.values().sum()
+    }
+}
+
+// A handful of stones, possibly with the same number engraved twice
+pub fn generate(rng: &mut Rng) -> Vec<String> {
+    let mut stones: Vec<Num> = (0..rng.range(1, 8)).map(|_| rng.range(0, 100000) as Num).collect();
+    if rng.chance(0.3) {
+        stones.push(*rng.pick(&stones));
+    }
+    vec![stones.iter().map(|s| s.to_string()).collect::<Vec<String>>().join(" ")]
+}
+
+impl aoc::Solution for Context {
+    fn parse(inp: Vec<String>) -> Result<Self, AocError> {
+        Context::new(inp)
+    }
+
+    fn part1(&self) -> Answer {
+        Context::part1(self, 25).into()
+    }
+
+    fn part2(&self) -> Answer {
+        Context::part1(self, 75).into()
+    }
+}
+
+#[allow(unused_imports)]
+#[cfg(test)]
+mod aoc11 {
+    use super::*;
+
+    #[test]
+    fn properties() {
+        prop::check(30, generate, |inp| {
+            let context = Context::new(inp).unwrap();
+            assert_eq!(context.part1(0), context.nums.len());
+            // Stones change independently of each other and never disappear
+            for blinks in [1, 10, 25] {
+                let alone: usize = context.nums.iter().map(|&n| Context { nums: vec![n] }.part1(blinks)).sum();
+                assert_eq!(context.part1(blinks), alone);
+                assert!(context.part1(blinks) >= context.part1(blinks - 1));
+            }
+        });
+    }
+
+    #[test]
+    fn example1() {
+        let text: Vec<String> = read_day(11, "example").expect("couldn't read input - aoc11");
+        let mut context = Context::new(text).unwrap();
+        //println!("split test: {:?}", context.split_number(&2000));
+        println!("context: {:?}", context);
+        let part1 = context.part1(25);
+        println!("Part1: {:?}", part1);
+        println!("Part2: {:?}", context.part1(75));
+    }
+
+    #[test]
+    fn actual() {
+        let text: Vec<String> = read_day(11, "input").expect("couldn't read input - aoc11");
+        let mut context = Context::new(text).unwrap();
+        let part1 = bench(|| context.part1(25), Some("part1"));
+        println!("Part1: {:?}", part1);
+        println!("Part2: {:?}", bench(|| context.part1(75), Some("part2")));
+    }
+}
\ No newline at end of file
@@ -1,81 +1,60 @@
-use crate::utils::{read_input, bench};
+use crate::read_day;
+use aoc::utils::bench;
+use aoc::utils::prop;
+use aoc::utils::rng::Rng;
+use aoc::Answer;
+use aoc::error::AocError;
+use aoc::utils::grid::Grid;
+use aoc::utils::render::{distinct, Canvas};
 use core::num;
-use std::fmt::{write, Debug, Formatter, Result};
+use std::fmt::{write, Debug, Formatter};
 use std::collections::{VecDeque, HashMap, HashSet};
 use rayon::prelude::*;
 
-#[derive(Debug)]
-struct Context {
-    plots: Vec<Vec<u8>>,
-    connected_components: Vec<Vec<usize>>,
-    r: usize,
-    c: usize,
+#[derive(Debug, Clone)]
+pub struct Context {
+    plots: Grid<u8>,
+    connected_components: Grid<usize>,
 }
 
 impl Context {
-    pub fn new(inp: Vec<String>) -> Context {
-        let (r, c) = (inp.len(), inp[0].len());
-        Context {
-            plots: inp.into_iter().map(|v| v.into_bytes()).collect(),
-            connected_components: vec![vec![0; c]; r],
-            r: r,
-            c: c
-        }
-    }
-
-    fn check_out_of_bounds(&self, ni: isize, nj: isize) -> bool {
-        ni < 0 || ni >= self.r as isize || nj < 0 || nj >= self.c as isize
-    }
-
-    fn check_out_of_boundsu(&self, i: usize, j: usize) -> bool {
-        self.check_out_of_bounds(i as isize, j as isize)
-    }
-
-    fn neighbors(i: usize, j: usize) -> [(isize, isize); 4] {
-        let (ii, ji) = (i as isize, j as isize);
-        [(ii + 1, ji), (ii - 1, ji), (ii, ji + 1), (ii, ji - 1)]
+    pub fn new(inp: Vec<String>) -> Result<Context, AocError> {
+        let plots = Grid::parse(&inp, |_, c| Ok(c as u8))?;
+        Ok(Context {
+            connected_components: Grid::new(plots.rows(), plots.cols(), 0),
+            plots: plots,
+        })
     }
 
-    fn visit_node(&mut self, i:usize, j: usize, visited: &mut Vec<Vec<bool>>, component_number: usize) {
-        if visited[i][j] {
+    fn visit_node(&mut self, i:usize, j: usize, visited: &mut Grid<bool>, component_number: usize) {
+        if visited[(i, j)] {
             return;
         }
-        visited[i][j] = true;
-        self.connected_components[i][j] = component_number;
-        for (ni, nj) in Context::neighbors(i, j) {
-            if  self.check_out_of_bounds(ni, nj) || self.plots[ni as usize][nj as usize] != self.plots[i][j] {
-                continue;
-            }
-            self.visit_node(ni as usize, nj as usize, visited, component_number);
+        visited[(i, j)] = true;
+        self.connected_components[(i, j)] = component_number;
+        let same: Vec<(usize, usize)> = self.plots.neighbours4((i, j)).filter(|&n| self.plots[n] == self.plots[(i, j)]).collect();
+        for (ni, nj) in same {
+            self.visit_node(ni, nj, visited, component_number);
         }
     }
 
     pub fn compute_connected_components(&mut self) -> usize {
         let mut component_counter = 0;
-        let mut visited: Vec<Vec<bool>> = vec![vec![false; self.c]; self.r];
-        for i in 0..self.r {
-            for j in 0..self.c {
-                if visited[i][j] {
-                    continue;
-                }
-                component_counter += 1;
-                self.visit_node(i, j, &mut visited, component_counter);
-
+        let mut visited: Grid<bool> = Grid::new(self.plots.rows(), self.plots.cols(), false);
+        for (i, j) in self.plots.positions() {
+            if visited[(i, j)] {
+                continue;
             }
+            component_counter += 1;
+            self.visit_node(i, j, &mut visited, component_counter);
         }
         component_counter
     }
 
+    // Sides of the plot that border another region or the edge of the map
     fn compute_perimeter(&self, i: usize, j: usize) -> usize {
-        let mut sum = 0;
-        for (ni, nj) in Context::neighbors(i, j) {
-            if self.check_out_of_bounds(ni, nj) {
-                sum += 1;
-            } else if self.connected_components[i][j] != self.connected_components[ni as usize][nj as usize] {
-                sum += 1;
-            }
-        }
-        sum
+        let component_id = self.connected_components[(i, j)];
+        4 - self.connected_components.neighbours4((i, j)).filter(|&n| self.connected_components[n] == component_id).count()
     }
 
     fn check_same_connected_component(&self, i: isize, j: isize, component_id: usize) -> bool {
@@ -83,28 +62,28 @@ impl Context {
     }
 
     fn check_same_connected_componentu(&self, i: usize, j: usize, component_id: usize) -> bool {
-        self.connected_components[i][j] == component_id
+        self.connected_components[(i, j)] == component_id
     }
 
     fn twodiff(&self, i1: isize, j1: isize, i2: isize, j2: isize, component_id: usize) -> bool {
         // Corner type1
         //    C
         //  C X
-        let one: bool = self.check_out_of_bounds(i1, j1) || !self.check_same_connected_component(i1, j1, component_id);
-        let two: bool = self.check_out_of_bounds(i2, j2) || !self.check_same_connected_component(i2, j2, component_id);
+        let one: bool = !self.plots.contains((i1, j1)) || !self.check_same_connected_component(i1, j1, component_id);
+        let two: bool = !self.plots.contains((i2, j2)) || !self.check_same_connected_component(i2, j2, component_id);
         one && two
     }
 
     fn threesame(&self, i1: isize, j1: isize, i2: isize, j2: isize, i3: isize, j3: isize, component_id: usize) -> bool {
-        let one = !self.check_out_of_bounds(i1, j1) && self.check_same_connected_component(i1, j1, component_id);
-        let two: bool = !self.check_out_of_bounds(i2, j2) && !self.check_same_connected_component(i2, j2, component_id);
-        let three: bool = self.check_out_of_bounds(i3, j3) || self.check_same_connected_component(i3, j3, component_id);
+        let one = self.plots.contains((i1, j1)) && self.check_same_connected_component(i1, j1, component_id);
+        let two: bool = self.plots.contains((i2, j2)) && !self.check_same_connected_component(i2, j2, component_id);
+        let three: bool = !self.plots.contains((i3, j3)) || self.check_same_connected_component(i3, j3, component_id);
         one && two && three
     }
 
     fn compute_sides(&self, i: usize, j: usize) -> usize {
         let mut corners = 0;
-        let component_id = self.connected_components[i][j];
+        let component_id = self.connected_components[(i, j)];
         let (ii, ji) = (i as isize, j as isize);
         // Top Left
         let (i1, j1) = (ii, ji - 1);
@@ -141,12 +120,10 @@ impl Context {
     pub fn part1(&mut self) -> (usize, Vec<usize>) {
         let num_components = self.compute_connected_components();
         let (mut area, mut perimeter) = (vec![0; num_components + 1], vec![0; num_components + 1]);
-        for i in 0..self.r {
-            for j in 0..self.c {
-                let component_id = self.connected_components[i][j];
-                area[component_id] += 1;
-                perimeter[component_id] += self.compute_perimeter(i, j);
-            }
+        for (i, j) in self.plots.positions() {
+            let component_id = self.connected_components[(i, j)];
+            area[component_id] += 1;
+            perimeter[component_id] += self.compute_perimeter(i, j);
         }
         (area.iter().zip(perimeter).map(|(a, p)| a*p).sum(), area)
     }
@@ -155,11 +132,9 @@ impl Context {
         //let mut sides = vec![HashSet::new(); num_components + 1];
         let mut sides = vec![0; num_components];
         //println!("Num components: {}", num_components);
-        for i in 0..self.r {
-            for j in 0..self.c {
-                let component_id = self.connected_components[i][j];
-                sides[component_id] += self.compute_sides(i, j);
-            }
+        for (i, j) in self.plots.positions() {
+            let component_id = self.connected_components[(i, j)];
+            sides[component_id] += self.compute_sides(i, j);
         }
         //println!("components: {:?}", self.connected_components);
         //println!("Sides: {:?}", sides);
@@ -167,6 +142,50 @@ impl Context {
     }
 }
 
+// Garden of a few plant types, grown in clumps
+pub fn generate(rng: &mut Rng) -> Vec<String> {
+    let (rows, cols) = (rng.range(1, 10) as usize, rng.range(1, 10) as usize);
+    let plants: Vec<char> = ['A', 'B', 'C', 'D', 'E'][..rng.range(1, 5) as usize].to_vec();
+    let mut garden: Vec<Vec<char>> = vec![];
+    for r in 0..rows {
+        let mut row: Vec<char> = vec![];
+        for c in 0..cols {
+            let plant = match rng.below(4) {
+                0 if r > 0 => garden[r - 1][c],
+                1 if c > 0 => row[c - 1],
+                _ => *rng.pick(&plants),
+            };
+            row.push(plant);
+        }
+        garden.push(row);
+    }
+    garden.iter().map(|row| row.iter().collect()).collect()
+}
+
+impl aoc::Solution for Context {
+    fn parse(inp: Vec<String>) -> Result<Self, AocError> {
+        Context::new(inp)
+    }
+
+    fn part1(&self) -> Answer {
+        let mut context = self.clone();
+        Context::part1(&mut context).0.into()
+    }
+
+    fn part2(&self) -> Answer {
+        let mut context = self.clone();
+        let (_, area) = Context::part1(&mut context);
+        Context::part2(&context, area.len(), area).into()
+    }
+
+    // Every region in its own colour, so neighbouring regions of the same plant stand apart
+    fn pictures(&self) -> Vec<(String, Canvas)> {
+        let mut context = self.clone();
+        context.compute_connected_components();
+        vec![("regions".to_owned(), Canvas::from_fn(&context.connected_components, |&id| distinct(id)))]
+    }
+}
+
 #[allow(unused_imports)]
 #[cfg(test)]
 mod aoc12 {
@@ -174,10 +193,27 @@ mod aoc12 {
 
     use super::*;
 
+    #[test]
+    fn properties() {
+        prop::check(50, generate, |inp| {
+            let (rows, cols) = (inp.len(), inp[0].len());
+            let field = vec!["A".repeat(cols); rows];
+            let mut context = Context::new(inp).unwrap();
+            let (part1, area) = context.part1();
+            // A side is made of at least one unit of fence
+            assert!(context.part2(area.len(), area) <= part1);
+            // A single rectangular region
+            let mut field = Context::new(field).unwrap();
+            let (part1, area) = field.part1();
+            assert_eq!(part1, rows * cols * 2 * (rows + cols));
+            assert_eq!(field.part2(area.len(), area), rows * cols * 4);
+        });
+    }
+
     #[test]
     fn example1() {
-        let text: Vec<String> = read_input("src/aoc12/example1").expect("couldn't read input - aoc12");
-        let mut context = Context::new(text);
+        let text: Vec<String> = read_day(12, "example1").expect("couldn't read input - aoc12");
+        let mut context = Context::new(text).unwrap();
         //let num_components = context.compute_connected_components();
         //println!("Num components: {}", num_components);
         //println!("Component Grid: {:?}", context.connected_components);
@@ -190,8 +226,8 @@ mod aoc12 {
 
    #[test]
     fn example2() {
-        let text: Vec<String> = read_input("src/aoc12/example2").expect("couldn't read input - aoc12");
-        let mut context = Context::new(text);
+        let text: Vec<String> = read_day(12, "example2").expect("couldn't read input - aoc12");
+        let mut context = Context::new(text).unwrap();
         //let num_components = context.compute_connected_components();
         //println!("Num components: {}", num_components);
         //println!("Component Grid: {:?}", context.connected_components);
@@ -204,8 +240,8 @@ mod aoc12 {
 
     #[test]
     fn example3() {
-        let text: Vec<String> = read_input("src/aoc12/example3").expect("couldn't read input - aoc12");
-        let mut context = Context::new(text);
+        let text: Vec<String> = read_day(12, "example3").expect("couldn't read input - aoc12");
+        let mut context = Context::new(text).unwrap();
         //let num_components = context.compute_connected_components();
         //println!("Num components: {}", num_components);
         //println!("Component Grid: {:?}", context.connected_components);
@@ -218,8 +254,8 @@ mod aoc12 {
 
     #[test]
     fn example4() {
-        let text: Vec<String> = read_input("src/aoc12/example4").expect("couldn't read input - aoc12");
-        let mut context = Context::new(text);
+        let text: Vec<String> = read_day(12, "example4").expect("couldn't read input - aoc12");
+        let mut context = Context::new(text).unwrap();
         //let num_components = context.compute_connected_components();
         //println!("Num components: {}", num_components);
         //println!("Component Grid: {:?}", context.connected_components);
@@ -232,8 +268,8 @@ mod aoc12 {
 
     #[test]
     fn example5() {
-        let text: Vec<String> = read_input("src/aoc12/example5").expect("couldn't read input - aoc12");
-        let mut context = Context::new(text);
+        let text: Vec<String> = read_day(12, "example5").expect("couldn't read input - aoc12");
+        let mut context = Context::new(text).unwrap();
         //let num_components = context.compute_connected_components();
         //println!("Num components: {}", num_components);
         //println!("Component Grid: {:?}", context.connected_components);
@@ -246,8 +282,8 @@ mod aoc12 {
 
    #[test]
     fn actual() {
-        let text: Vec<String> = read_input("src/aoc12/input").expect("couldn't read input - aoc12");
-        let mut context = Context::new(text);
+        let text: Vec<String> = read_day(12, "input").expect("couldn't read input - aoc12");
+        let mut context = Context::new(text).unwrap();
         let num_components = context.compute_connected_components();
         //println!("Num components: {}", num_components);
         //println!("Component Grid: {:?}", context.connected_components);
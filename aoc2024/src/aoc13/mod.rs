@@ -0,0 +1,266 @@
+use crate::read_day;
+use aoc::utils::bench;
+use aoc::utils::prop;
+use aoc::utils::rng::Rng;
+use aoc::Answer;
+use aoc::error::AocError;
+use aoc::utils::parse::{integers_n, records};
+use core::num;
+use std::fmt::{write, Debug, Formatter};
+use std::collections::{VecDeque, HashMap, HashSet};
+use rayon::prelude::*;
+use nalgebra::{Matrix2, Vector2};
+
+type Num = f64;
+
+#[derive(Debug, Clone)]
+struct Equation {
+    mat: Matrix2<Num>,
+    vec: Vector2<Num>
+}
+
+
+impl Equation {
+    // Constructor method
+    pub fn new(mat: Matrix2<Num>, vec: Vector2<Num>) -> Self {
+        Equation { mat, vec }
+    }
+
+    // Method to solve the equation using matrix inversion
+    pub fn solve(&self) -> Option<Vector2<Num>> {
+        // Check if the matrix is invertible
+        if self.mat.determinant() == 0.0 {
+            //println!("Eq: {:?} Determinant zero", self);
+            None
+        } else {
+            let inv_mat = self.mat.try_inverse().unwrap();
+            let r = inv_mat * self.vec;
+            //println!("Eq: {:?} solution {:?}", self, r);
+            Some(inv_mat * self.vec)
+        }
+    }
+
+    // Cheapest presses when both buttons move the claw in the same direction, so any number of
+    // combinations may reach the prize. `limit` caps the presses of each button.
+    pub fn cheapest_on_line(&self, limit: Option<i128>) -> Option<usize> {
+        let [ax, ay, bx, by, px, py] = [self.mat[(0, 0)], self.mat[(1, 0)], self.mat[(0, 1)], self.mat[(1, 1)], self.vec[0], self.vec[1]]
+            .map(|v| v as i64 as i128);
+        // a * ax + b * bx = px, with a = a0 + k * step for whole k
+        let (g, inv, _) = ext_gcd(ax, bx);
+        if px % g != 0 {
+            return None;
+        }
+        let step = bx / g;
+        let a0 = ((px / g) % step * (inv % step)).rem_euclid(step);
+        // Presses of A that keep both counts in range
+        let limit = limit.unwrap_or(i128::MAX / 4);
+        let lo = (px - limit.saturating_mul(bx)).max(0);
+        let lo = (lo + ax - 1) / ax;
+        let hi = (px / ax).min(limit);
+        let first = lo + (a0 - lo).rem_euclid(step);
+        let last = hi - (hi - a0).rem_euclid(step);
+        // The cost changes linearly with the presses of A, so one of the ends is the cheapest.
+        // The buttons are parallel, so the Y equation holds either for all of them or none.
+        [first, last].into_iter()
+            .filter(|&a| a >= lo && a <= hi)
+            .map(|a| (a, (px - a * ax) / bx))
+            .filter(|&(a, b)| a * ay + b * by == py)
+            .map(|(a, b)| (3 * a + b) as usize)
+            .min()
+    }
+
+    pub fn add(&mut self, val: Num) {
+        self.vec[0] += val;
+        self.vec[1] += val;
+    }
+}
+
+// gcd of `a` and `b` with x and y such that a * x + b * y = gcd
+fn ext_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    if b == 0 {
+        return (a, 1, 0);
+    }
+    let (g, x, y) = ext_gcd(b, a % b);
+    (g, y, x - (a / b) * y)
+}
+
+#[derive(Debug, Clone)]
+pub struct Context {
+    equations: Vec<Equation>
+}
+
+impl Context {
+    pub fn new(inp: Vec<String>) -> Result<Context, AocError> {
+        let mut eqs = vec![];
+        for record in records(&inp, 3) {
+            let i = record.start;
+            let [ax, ay] = integers_n::<Num, 2>(i, record.line(0, "button A")?, "`Button A: X+a, Y+b`")?;
+            let [bx, by] = integers_n::<Num, 2>(i + 1, record.line(1, "button B")?, "`Button B: X+a, Y+b`")?;
+            let [px, py] = integers_n::<Num, 2>(i + 2, record.line(2, "prize")?, "`Prize: X=a, Y=b`")?;
+            if [ax, ay, bx, by].iter().any(|&v| v <= 0.0) {
+                return Err(AocError::malformed(i, "buttons have to move the claw forward on both axes"));
+            }
+            eqs.push(Equation::new(Matrix2::new(ax, bx, ay, by), Vector2::new(px, py)));
+        }
+        Ok(Context {
+            equations: eqs
+        })
+    }
+
+    fn check_valid(n: Num) -> bool {
+        //println!("num: {}, fract_abs: {}, round: {}", n, n.fract().abs(), n.round());
+        let fractional_part = n.fract().abs();
+        // A whole number of presses, at most 100 of them
+        (fractional_part < 1e-4 || fractional_part > 0.9999) && n.round() >= 0.0 && n.round() <= 100.0
+    }
+
+    fn get_cost(v: &Vector2<Num>) -> usize {
+        //println!("vector: {:?}", v);
+
+        if Context::check_valid(v[0]) && Context::check_valid(v[1]) {
+            //println!("valid");
+            return v[0].round() as usize * 3 + v[1].round() as usize;
+        }
+        0
+    }
+
+    fn check_valid_part2(n: Num) -> bool {
+        //println!("num: {}, fract_abs: {}, round: {}", n, n.fract().abs(), n.round());
+        let fractional_part = n.fract().abs();
+        fractional_part < 1e-2 || fractional_part > 0.99
+    }
+
+    fn get_cost_part2(v: &Vector2<Num>) -> usize {
+        //println!("vector: {:?}", v);
+        if Context::check_valid_part2(v[0]) && Context::check_valid_part2(v[1]) {
+            //println!("valid");
+            return v[0].round() as usize * 3 + v[1].round() as usize;
+        }
+        0
+    }
+
+    pub fn part1(&self) -> usize {
+        self.equations.iter().map(|eq| {
+            match eq.solve() {
+                None => eq.cheapest_on_line(Some(100)).unwrap_or(0),
+                Some(v) => Context::get_cost(&v),
+            }
+        }).sum()
+    }
+
+    pub fn part2(&mut self) -> usize {
+        let val: Num = 10000000000000.0;
+        self.equations.iter_mut().map(|eq| {
+            eq.add(val);
+            match eq.solve() {
+                None => eq.cheapest_on_line(None).unwrap_or(0),
+                Some(v) => Context::get_cost_part2(&v),
+            }
+        }).sum()
+    }
+}
+
+// Claw machines, most with a prize some combination of presses reaches. Some have buttons that
+// move the claw in the same direction, where several combinations can reach the prize.
+pub fn generate(rng: &mut Rng) -> Vec<String> {
+    let mut lines = vec![];
+    for k in 0..rng.range(1, 10) {
+        let (ax, ay, bx, by) = if rng.chance(0.2) {
+            let (ux, uy) = (rng.range(1, 20), rng.range(1, 20));
+            let (ka, kb) = (rng.range(1, 5), rng.range(1, 5));
+            (ka * ux, ka * uy, kb * ux, kb * uy)
+        } else {
+            (rng.range(1, 99), rng.range(1, 99), rng.range(1, 99), rng.range(1, 99))
+        };
+        let (px, py) = if rng.chance(0.7) {
+            let (a, b) = (rng.range(0, 100), rng.range(0, 100));
+            (a * ax + b * bx, a * ay + b * by)
+        } else {
+            (rng.range(100, 20000), rng.range(100, 20000))
+        };
+        if k > 0 {
+            lines.push(String::new());
+        }
+        lines.push(format!("Button A: X+{}, Y+{}", ax, ay));
+        lines.push(format!("Button B: X+{}, Y+{}", bx, by));
+        lines.push(format!("Prize: X={}, Y={}", px, py));
+    }
+    lines
+}
+
+impl aoc::Solution for Context {
+    fn parse(inp: Vec<String>) -> Result<Self, AocError> {
+        Context::new(inp)
+    }
+
+    fn part1(&self) -> Answer {
+        Context::part1(self).into()
+    }
+
+    fn part2(&self) -> Answer {
+        let mut context = self.clone();
+        Context::part2(&mut context).into()
+    }
+}
+
+#[allow(unused_imports)]
+#[cfg(test)]
+mod aoc13 {
+    use super::*;
+
+    #[test]
+    fn properties() {
+        prop::check(50, generate, |inp| {
+            let context = Context::new(inp.clone()).unwrap();
+            let mut total = 0;
+            for record in records(&inp, 3) {
+                let [ax, ay, bx, by, px, py] = integers_n::<usize, 6>(0, &record.lines.join(" "), "a machine").unwrap();
+                let cost = Context::new(record.lines.to_vec()).unwrap().part1();
+                // Any tokens spent pay for presses that reach the prize
+                if cost > 0 {
+                    assert!((0..=cost / 3).any(|a| a * ax + (cost - 3 * a) * bx == px && a * ay + (cost - 3 * a) * by == py));
+                }
+                total += cost;
+            }
+            // Machines are independent
+            assert_eq!(context.part1(), total);
+        });
+    }
+
+    #[test]
+    fn collinear() {
+        let inp = vec!["Button A: X+1, Y+1".to_owned(), "Button B: X+2, Y+2".to_owned(), "Prize: X=3, Y=3".to_owned()];
+        let mut context = Context::new(inp).unwrap();
+        assert_eq!(context.part1(), 4);
+        assert_eq!(context.part2(), 3 + 5000000000001);
+        // Pressing A is cheaper when it moves the claw more than three times as far
+        let inp = vec!["Button A: X+8, Y+4".to_owned(), "Button B: X+2, Y+1".to_owned(), "Prize: X=18, Y=9".to_owned()];
+        assert_eq!(Context::new(inp).unwrap().part1(), 7);
+        // Off the line, or on it but out of step with the buttons
+        let inp = vec!["Button A: X+2, Y+2".to_owned(), "Button B: X+4, Y+4".to_owned(), "Prize: X=3, Y=3".to_owned()];
+        assert_eq!(Context::new(inp).unwrap().part1(), 0);
+        let inp = vec!["Button A: X+1, Y+1".to_owned(), "Button B: X+2, Y+2".to_owned(), "Prize: X=3, Y=4".to_owned()];
+        assert_eq!(Context::new(inp).unwrap().part1(), 0);
+    }
+
+    #[test]
+    fn example1() {
+        let text: Vec<String> = read_day(13, "example").expect("couldn't read input - aoc13");
+        let mut context = Context::new(text).unwrap();
+        //println!("context: {:?}", context);
+        let part1 = context.part1();
+        println!("Example part1: {}", part1);
+        //let (part1, area) = context.part1();
+        //println!("Example1 Part1: {:?}", part1);
+        println!("Example1 Part2: {:?}", context.part2());
+    }
+
+   #[test]
+    fn actual() {
+        let text: Vec<String> = read_day(13, "input").expect("couldn't read input - aoc13");
+        let mut context = Context::new(text).unwrap();
+        let part1 = bench(|| context.part1(), Some("part1"));
+        println!("Part1: {:?}", part1);
+        println!("Part2: {:?}", bench(|| context.part2(), Some("part2")));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,320 @@
+use crate::read_day;
+use aoc::utils::bench;
+use aoc::utils::prop;
+use aoc::utils::rng::Rng;
+use aoc::Answer;
+use aoc::error::AocError;
+use aoc::utils::parse::integers_n;
+use core::num;
+use std::fmt::{self, write, Debug, Formatter};
+use std::collections::{VecDeque, HashMap, HashSet};
+use std::hash::Hash;
+use rayon::prelude::*;
+use aoc::utils::render::{Canvas, BLACK, WHITE};
+use aoc::utils::frames::Recorder;
+use aoc::utils::grid::Grid;
+use aoc::utils::render::{Palette, GREY};
+use aoc::debugger::{Event, Simulation};
+
+type Num = i32;
+
+type Pos = (Num, Num);
+
+// Width and height of the floor in the puzzle input. The example's is 11x7.
+const FLOOR: (Num, Num) = (101, 103);
+
+#[derive(Clone)]
+struct Bot {
+    position: Pos,
+    velocity: Pos
+}
+
+impl Debug for Bot {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "Bot {{ p=({}, {}), v=({}, {}) }}", 
+               self.position.0, self.position.1, 
+               self.velocity.0, self.velocity.1)
+    }
+}
+
+impl Bot {
+    // Constructor method
+    pub fn new(position: Pos, velocity: Pos) -> Self {
+        Bot { position, velocity }
+    }
+
+    // Method to update the position based on velocity
+    pub fn update_pos(&mut self, wallx: Num, wally: Num) {
+        let (x, y) = self.position;
+        let (vx, vy) = self.velocity;
+        self.position = (Bot::modulo_arithmetic(x, wallx, vx), Bot::modulo_arithmetic(y, wally, vy));
+    }
+
+    fn modulo_arithmetic(x: i32, modulus: i32, add: i32) -> i32 {
+        return (x + add).rem_euclid(modulus);
+        /*
+        if result < 0 {
+            result + modulus
+        } else {
+            result % modulus
+        }
+        */
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Context {
+    bots: Vec<Bot>,
+    wallx: Num,
+    wally: Num,
+}
+
+impl Context {
+    // Robots on a floor `wallx` wide and `wally` high
+    pub fn new(inp: Vec<String>, wallx: Num, wally: Num) -> Result<Context, AocError> {
+        let mut bots: Vec<Bot> = inp.iter().enumerate().map(|(i, s)| Context::parse_line(i, s)).collect::<Result<_, AocError>>()?;
+        if let Some(k) = bots.iter().position(|b| !(0..wallx).contains(&b.position.0) || !(0..wally).contains(&b.position.1)) {
+            return Err(AocError::malformed(k, &format!("robot lies outside the {}x{} floor", wallx, wally)));
+        }
+        // A step wraps around the floor, so only velocities within its size matter. Positions
+        // then stay far from overflowing.
+        for b in &mut bots {
+            b.velocity = (b.velocity.0.rem_euclid(wallx), b.velocity.1.rem_euclid(wally));
+        }
+        Ok(Context {
+            bots: bots,
+            wallx: wallx,
+            wally: wally
+        })
+    }
+
+    fn parse_line(i: usize, s: &String) -> Result<Bot, AocError> {
+        let [px, py, vx, vy] = integers_n::<Num, 4>(i, s, "`p=x,y v=dx,dy`")?;
+        Ok(Bot::new((px, py), (vx, vy)))
+    }
+
+    fn quadrant_count(&self, wallx: Num, wally: Num) -> Num {
+        let mx = wallx / 2;
+        let my = wally / 2;
+        let mut quadrants = [0,0,0,0];
+        for b in &self.bots {
+            // Axes
+            if b.position.0 == mx || b.position.1 == my {
+                continue;
+            }
+            let left = b.position.0 < mx;
+            let top = b.position.1 < my;
+            match (left, top) {
+                (true, true) => quadrants[1] += 1,
+                (false, true) => quadrants[0] += 1,
+                (true, false) => quadrants[2] += 1,
+                (false, false) => quadrants[3] += 1
+            }
+        }
+        //println!("quandrant: {:?}", quadrants);
+        quadrants.into_iter().fold(1, |acc, x| acc * x)
+    }
+
+    fn symmetry_score(&self, wallx: Num, wally: Num) -> usize {
+        let mut sym_count = 0;
+        let mx = wallx / 2;
+        let my = wally / 2;
+        let set: HashSet<(Num, Num)> = self.bots.iter().map(|b| b.position).collect();
+        set.len()
+    }
+
+    pub fn part1(&mut self, N: usize, wallx: Num, wally: Num) -> Num {
+        //println!("Start: {:?}", self);
+        for i in 0..N {
+            for b in &mut self.bots {
+                b.update_pos(wallx, wally);
+                //println!("{:?}", b);
+            }
+        }
+        self.quadrant_count(wallx, wally)
+    }
+
+    // Returns the first iteration at which no two bots overlap
+    pub fn part2(&mut self, N: usize, wallx: Num, wally: Num) -> Option<usize> {
+        let mut map: HashMap<Num, Vec<usize>> = HashMap::new();
+        let mut max_score = 0;
+        for i in 0..N {
+            for b in &mut self.bots {
+                b.update_pos(wallx, wally);
+            }
+            let sym_score = self.symmetry_score(wallx, wally);
+            if sym_score == self.bots.len() {
+                return Some(i + 1);
+            }
+        }
+        None
+    }
+
+    // Robots in white on the black floor
+    fn picture(&self) -> Canvas {
+        let (wallx, wally) = (self.wallx, self.wally);
+        let mut canvas = Canvas::new(wally as usize, wallx as usize, BLACK);
+        canvas.paint(self.bots.iter().map(|b| (b.position.1 as usize, b.position.0 as usize)), WHITE);
+        canvas
+    }
+
+}
+
+// The robots second by second, for one full cycle of the floor. Seconds with no two robots on
+// the same tile, which part 2 looks for, are tagged "apart".
+struct Seconds {
+    context: Context,
+    bots: Vec<Bot>,
+    second: usize,
+}
+
+impl Simulation for Seconds {
+    fn reset(&mut self) {
+        self.bots = self.context.bots.clone();
+        self.second = 0;
+    }
+
+    fn step(&mut self) -> Option<Event> {
+        let (wallx, wally) = (self.context.wallx, self.context.wally);
+        if self.second == (wallx * wally) as usize {
+            return None;
+        }
+        for b in &mut self.bots {
+            b.update_pos(wallx, wally);
+        }
+        self.second += 1;
+        let tiles: HashSet<Pos> = self.bots.iter().map(|b| b.position).collect();
+        Some(Event::new(format!("second {}", self.second)).tag("apart", tiles.len() == self.bots.len()))
+    }
+
+    // Number of robots on each tile, like the puzzle draws them
+    fn view(&self) -> Grid<char> {
+        let (wallx, wally) = (self.context.wallx, self.context.wally);
+        let mut view = Grid::new(wally as usize, wallx as usize, '.');
+        for b in &self.bots {
+            let tile = &mut view[(b.position.1 as usize, b.position.0 as usize)];
+            *tile = match *tile {
+                '.' => '1',
+                '9' => '9',
+                n => (n as u8 + 1) as char,
+            };
+        }
+        view
+    }
+
+    fn palette(&self) -> Palette<char> {
+        Palette::new(WHITE).with('.', GREY)
+    }
+}
+
+// Robots on the 11x7 example floor
+pub fn generate(rng: &mut Rng) -> Vec<String> {
+    (0..rng.range(1, 30))
+        .map(|_| format!("p={},{} v={},{}", rng.range(0, 10), rng.range(0, 6), rng.range(-5, 5), rng.range(-5, 5)))
+        .collect()
+}
+
+impl aoc::Solution for Context {
+    fn parse(inp: Vec<String>) -> Result<Self, AocError> {
+        Context::new(inp, FLOOR.0, FLOOR.1)
+    }
+
+    fn part1(&self) -> Answer {
+        let mut context = self.clone();
+        Context::part1(&mut context, 100, self.wallx, self.wally).into()
+    }
+
+    // The robots are back where they started after wallx * wally seconds
+    fn part2(&self) -> Answer {
+        let mut context = self.clone();
+        Context::part2(&mut context, (self.wallx * self.wally) as usize, self.wallx, self.wally).into()
+    }
+
+    // The floor once the robots show the picture part 2 looks for
+    fn pictures(&self) -> Vec<(String, Canvas)> {
+        let (wallx, wally) = (self.wallx, self.wally);
+        let mut context = self.clone();
+        Context::part2(&mut context, (wallx * wally) as usize, wallx, wally);
+        vec![("robots".to_owned(), context.picture())]
+    }
+
+    // One frame per second until the picture of part 2, or for a whole cycle of the floor
+    // if there is none
+    fn animations(&self, every: usize) -> Vec<(String, Vec<Canvas>)> {
+        let (wallx, wally) = (self.wallx, self.wally);
+        let seconds = Context::part2(&mut self.clone(), (wallx * wally) as usize, wallx, wally).unwrap_or((wallx * wally) as usize);
+        let mut context = self.clone();
+        let mut recorder = Recorder::new(every);
+        recorder.frame(context.picture());
+        for _ in 0..seconds {
+            for b in &mut context.bots {
+                b.update_pos(wallx, wally);
+            }
+            recorder.step(|| context.picture());
+        }
+        vec![("robots".to_owned(), recorder.into_frames())]
+    }
+
+    fn simulations(&self) -> Vec<(String, Box<dyn Simulation>)> {
+        vec![("robots".to_owned(), Box::new(Seconds { context: self.clone(), bots: self.bots.clone(), second: 0 }))]
+    }
+}
+
+#[allow(unused_imports)]
+#[cfg(test)]
+mod aoc14 {
+    use super::*;
+
+    #[test]
+    fn properties() {
+        prop::check(50, generate, |inp| {
+            let (wallx, wally) = (11, 7);
+            let context = Context::new(inp, wallx, wally).unwrap();
+            // Every robot is back where it started after 11 * 7 seconds
+            let mut later = context.clone();
+            let mut again = context.clone();
+            assert_eq!(later.clone().part1(100, wallx, wally), again.part1(100 + 77, wallx, wally));
+            // Waiting in one go or in two steps ends the same
+            later.part1(60, wallx, wally);
+            assert_eq!(later.part1(40, wallx, wally), context.clone().part1(100, wallx, wally));
+        });
+    }
+
+//    #[test]
+    fn example1() {
+        let text: Vec<String> = vec!["p=2,4 v=2,-3".to_owned()];
+        let mut context = Context::new(text, 11, 7).unwrap();
+        //let part1 = context.part1(5, 11, 7);
+        //println!("Example part1: {}", part1);
+        //let (part1, area) = context.part1();
+        //println!("Example1 Part1: {:?}", part1);
+        //println!("Example1 Part2: {:?}", context.part2());
+    }
+
+    #[test]
+    fn example2() {
+        let text: Vec<String> = read_day(14, "example").expect("couldn't read input - aoc14");
+        let mut context = Context::new(text, 11, 7).unwrap();
+        assert_eq!(context.part1(100, 11, 7), 12);
+        //println!("context: {:?}", context);
+        //let part1 = context.part1(100, 11, 7);
+        //println!("Example part1: {}", part1);
+        //let (part1, area) = context.part1();
+        //println!("Example1 Part1: {:?}", part1);
+        //println!("Example1 Part2: {:?}", context.part2());
+        //context.part2(1000, 11, 7);
+    }
+
+   #[test]
+    fn actual() {
+        // 222899040
+        // 224583840
+        let text: Vec<String> = read_day(14, "input").expect("couldn't read input - aoc14");
+        let mut context = Context::new(text, 101, 103).unwrap();
+        //let part1 = bench(|| context.part1(100, 101, 103), Some("part1"));
+        //println!("Part1: {:?}", part1);
+        //println!("Part2: {:?}", bench(|| context.part2(), Some("part2")));
+        let part2 = bench(|| context.part2(100000, 101, 103), Some("Part2"));
+        println!("Part2: {:?}", part2);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,457 @@
+use crate::read_day;
+use aoc::utils::bench;
+use aoc::utils::prop;
+use aoc::utils::rng::Rng;
+use aoc::Answer;
+use aoc::error::AocError;
+use aoc::utils::parse::split_blank;
+use aoc::utils::grid::Grid;
+use aoc::utils::geometry::{Dir, Point};
+use core::num;
+use std::fmt::{self, write, Debug, Formatter};
+use std::collections::{VecDeque, HashMap, HashSet};
+use std::hash::Hash;
+use rayon::prelude::*;
+use aoc::utils::render::{Canvas, Palette, BROWN, GREY, RED, WHITE};
+use aoc::utils::frames::Recorder;
+use aoc::debugger::{Event, Simulation};
+
+type Pos = Point;
+
+#[derive(Copy, Clone, PartialEq)]
+enum Type {
+    Bot,
+    Box,
+    Block,
+    BoxLeft,
+    BoxRight,
+    Empty
+}
+
+impl Type {
+    fn symbol(self) -> char {
+        match self {
+            Type::Bot => '@',
+            Type::Box => 'O',
+            Type::Block => '#',
+            Type::Empty => '.',
+            Type::BoxLeft => '[',
+            Type::BoxRight => ']'
+        }
+    }
+}
+
+impl Debug for Type {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.symbol())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Context {
+    bot: Pos,
+    moves: Vec<Dir>,
+    matrix: Grid<Type>,
+    bot2: Pos,
+    matrix2: Grid<Type>,
+}
+
+impl Context {
+    pub fn new(inp: Vec<String>) -> Result<Context, AocError> {
+        let (map, instructions) = split_blank(&inp, "a blank line after the warehouse map")?;
+        let mut bot = Point::new(-1, -1);
+        let matrix = Grid::parse(map.lines, |(i, j), c| match c {
+            '#' => Ok(Type::Block),
+            '@' => {
+                bot = Point::from_index((i, j));
+                Ok(Type::Bot)
+            },
+            'O' => Ok(Type::Box),
+            '.' => Ok(Type::Empty),
+            _ => Err(AocError::unexpected_char(i, j, c)),
+        })?;
+        if bot == Point::new(-1, -1) {
+            return Err(AocError::missing("robot '@'"));
+        }
+        // Everything except the robot is twice as wide in the second warehouse
+        let wide = matrix.iter()
+            .flat_map(|(_, t)| match t {
+                Type::Block => [Type::Block, Type::Block],
+                Type::Bot => [Type::Bot, Type::Empty],
+                Type::Box => [Type::BoxLeft, Type::BoxRight],
+                _ => [Type::Empty, Type::Empty],
+            })
+            .collect();
+        let matrix2 = Grid::from_vec(matrix.rows(), 2 * matrix.cols(), wide);
+        let bot2 = Point::new(bot.r, 2 * bot.c);
+        let mut moves = vec![];
+        for (k, line) in instructions.enumerate() {
+            for (j, c) in line.chars().enumerate() {
+                moves.push(Dir::from_arrow(c).ok_or(AocError::unexpected_char(k, j, c))?);
+            }
+        }
+        // Moves only stop at walls, so the map has to be closed off by them
+        let (rows, cols) = (matrix.rows(), matrix.cols());
+        if let Some(((i, _), _)) = matrix.iter().find(|&((i, j), &t)| (i == 0 || j == 0 || i == rows - 1 || j == cols - 1) && t != Type::Block) {
+            return Err(AocError::malformed(i, "the warehouse map has to be surrounded by walls '#'"));
+        }
+        Ok(Context {
+            bot: bot,
+            moves: moves,
+            matrix: matrix,
+            bot2: bot2,
+            matrix2: matrix2
+        })
+    }
+
+    pub fn print_matrix(&self) {
+        println!("{:?}", self.matrix);
+    }
+
+    pub fn print_matrix2(&self) {
+        println!("{:?}", self.matrix2);
+    }
+
+    pub fn compute_gps(&self) -> usize {
+        self.matrix.find_all(&Type::Box).map(|(i, j)| 100 * i + j).sum()
+    }
+
+    pub fn compute_gps2(&self) -> usize {
+        self.matrix2.find_all(&Type::BoxLeft).map(|(i, j)| 100 * i + j).sum()
+    }
+
+    fn move_bot(bot: &mut Pos, matrix: &mut Grid<Type>, moves: &Vec<Dir>, i: usize) {
+        //println!("Bot position: {:?}; Move: {:?}", bot, moves[i]);
+        let dir = moves[i];
+        let nex_bot = *bot + dir;
+        if matrix[nex_bot] == Type::Empty {
+            matrix[nex_bot] = Type::Bot;
+            matrix[*bot] = Type::Empty;
+            *bot = nex_bot;
+            return;
+        }
+        if matrix[nex_bot] == Type::Block {
+            return;
+        }
+        // If block ->
+        // Keep moving in that direction until we hit one of the following
+        // 1. Block -> Return
+        // 2. Box -> Continue
+        // 3. Empty -> Move all the blocks + bot by 1.
+        let mut cur = nex_bot;
+        loop {
+            if matrix[cur] == Type::Block {
+                return;
+            }
+            if matrix[cur] == Type::Box || matrix[cur] == Type::BoxLeft || matrix[cur] == Type::BoxRight {
+                cur += dir;
+                continue;
+            }
+            break;
+        }
+        //println!("Next Empty position at: {:?}", cur);
+        loop {
+            let prev = cur - dir;
+            matrix[cur] = matrix[prev];
+            if cur == nex_bot {
+                break;
+            }
+            cur = prev;
+        }
+        matrix[nex_bot] = Type::Bot;
+        matrix[*bot] = Type::Empty;
+        *bot = nex_bot;
+    }
+
+    pub fn part1(&mut self) -> usize {
+        //self.print_matrix();
+        for i in 0..self.moves.len() {
+            //println!("");
+            Context::move_bot(&mut self.bot, &mut self.matrix, &self.moves, i);
+        }
+        //self.print_matrix();
+        self.compute_gps()
+    }
+
+    fn check_column(cur_pos: Pos, m: &Dir, matrix: &Grid<Type>) -> bool {
+        if !m.is_vertical() {
+            panic!("Cannot do this for lateral moves");
+        }
+        let nex_pos = cur_pos + *m;
+        if matrix[nex_pos] == Type::Block {
+            return false;
+        }
+        if matrix[nex_pos] == Type::Empty {
+            return true;
+        }
+        if matrix[nex_pos] == Type::BoxLeft {
+            return Context::check_column(nex_pos, m, matrix) &&
+                Context::check_column(nex_pos + Dir::E, m, matrix);
+        }
+        if matrix[nex_pos] == Type::BoxRight {
+            return Context::check_column(nex_pos, m, matrix) &&
+            Context::check_column(nex_pos + Dir::W, m, matrix);
+        }
+        panic!("Unknown type for check_column: {:?}", matrix[nex_pos]);
+    }
+
+    fn move_column(cur_pos: Pos, m: &Dir, matrix: &mut Grid<Type>) -> Pos {
+        if !m.is_vertical() {
+            panic!("Cannot do this for lateral moves");
+        }
+        let nex_pos = cur_pos + *m;
+        if matrix[nex_pos] == Type::Block {
+            panic!("Cannot move {:?} with move {:?}", cur_pos, m);
+        }
+        if matrix[nex_pos] == Type::Empty {
+            matrix[nex_pos] = matrix[cur_pos];
+            matrix[cur_pos] = Type::Empty;
+            //println!("Cur_pos: {:?}, Value: {:?}, new_pos: {:?}", cur_pos, matrix[cur_pos], nex_pos);
+            return nex_pos;
+        }
+        if matrix[nex_pos] == Type::BoxLeft {
+            Context::move_column(nex_pos, m, matrix);
+            Context::move_column(nex_pos + Dir::E, m, matrix);
+            matrix[nex_pos] = matrix[cur_pos];
+            matrix[cur_pos] = Type::Empty;
+            //println!("Cur_pos: {:?}, Value: {:?}, new_pos: {:?}", cur_pos, matrix[cur_pos], nex_pos);
+            return nex_pos;
+        }
+        if matrix[nex_pos] == Type::BoxRight {
+            Context::move_column(nex_pos, m, matrix);
+            Context::move_column(nex_pos + Dir::W, m, matrix);
+            matrix[nex_pos] = matrix[cur_pos];
+            matrix[cur_pos] = Type::Empty;
+            //println!("Cur_pos: {:?}, Value: {:?}, new_pos: {:?}", cur_pos, matrix[cur_pos], nex_pos);
+            return nex_pos;
+        }
+        panic!("Unknown type for check_column: {:?}", matrix[nex_pos]);
+    }
+
+    fn move_bot_2(bot: &mut Pos, matrix: &mut Grid<Type>, moves: &Vec<Dir>, i: usize) {
+        if moves[i].is_horizontal() {
+            Context::move_bot(bot, matrix, moves, i);
+            return;
+        }
+        //println!("Vertical move");
+        if Context::check_column(*bot, &moves[i], &matrix) {
+            let nex_pos = Context::move_column(*bot, &moves[i], matrix);
+            matrix[*bot] = Type::Empty;
+            *bot = nex_pos;
+        }
+    }
+
+    pub fn part2(&mut self) -> usize {
+        //self.print_matrix2();
+         for i in 0..self.moves.len() {
+            //println!("");
+            Context::move_bot_2(&mut self.bot2, &mut self.matrix2, &self.moves, i);
+            //self.print_matrix2();
+         }
+        //self.print_matrix2();  
+        self.compute_gps2()
+    }
+
+    fn palette() -> Palette<Type> {
+        Palette::new(WHITE)
+            .with(Type::Block, GREY)
+            .with(Type::Bot, RED)
+            .with(Type::Box, BROWN)
+            .with(Type::BoxLeft, BROWN)
+            .with(Type::BoxRight, BROWN)
+    }
+
+}
+
+// The robot's moves in one of the warehouses. Moves that push boxes are tagged "push", moves
+// that leave the robot where it was "blocked", and vertical pushes in the wide warehouse
+// "column".
+struct Moves {
+    context: Context,
+    wide: bool,
+    bot: Pos,
+    matrix: Grid<Type>,
+    next: usize,
+}
+
+impl Moves {
+    fn new(context: Context, wide: bool) -> Moves {
+        let (bot, matrix) = if wide { (context.bot2, context.matrix2.clone()) } else { (context.bot, context.matrix.clone()) };
+        Moves { context, wide, bot, matrix, next: 0 }
+    }
+}
+
+impl Simulation for Moves {
+    fn reset(&mut self) {
+        *self = Moves::new(self.context.clone(), self.wide);
+    }
+
+    fn step(&mut self) -> Option<Event> {
+        let i = self.next;
+        let dir = *self.context.moves.get(i)?;
+        let (before, ahead) = (self.bot, self.matrix[self.bot + dir]);
+        if self.wide {
+            Context::move_bot_2(&mut self.bot, &mut self.matrix, &self.context.moves, i);
+        } else {
+            Context::move_bot(&mut self.bot, &mut self.matrix, &self.context.moves, i);
+        }
+        self.next += 1;
+        let pushing = ahead != Type::Empty && ahead != Type::Block;
+        Some(Event::new(format!("move {} of {}: {}", i + 1, self.context.moves.len(), dir.to_arrow().unwrap()))
+            .tag("push", pushing && self.bot != before)
+            .tag("blocked", self.bot == before)
+            .tag("column", self.wide && pushing && dir.is_vertical()))
+    }
+
+    fn view(&self) -> Grid<char> {
+        self.matrix.map(|t| t.symbol())
+    }
+
+    fn palette(&self) -> Palette<char> {
+        Palette::new(BROWN).with('.', WHITE).with('#', GREY).with('@', RED)
+    }
+}
+
+// Walled warehouse with boxes, inner walls, one robot and a few lines of moves
+pub fn generate(rng: &mut Rng) -> Vec<String> {
+    let (rows, cols) = (rng.range(3, 10) as usize, rng.range(3, 10) as usize);
+    let mut map: Vec<Vec<char>> = (0..rows)
+        .map(|r| (0..cols)
+            .map(|c| if r == 0 || c == 0 || r == rows - 1 || c == cols - 1 {
+                '#'
+            } else {
+                *rng.pick(&['.', '.', '.', 'O', 'O', '#'])
+            })
+            .collect())
+        .collect();
+    map[rng.range(1, rows as i64 - 2) as usize][rng.range(1, cols as i64 - 2) as usize] = '@';
+    let mut lines: Vec<String> = map.iter().map(|row| row.iter().collect()).collect();
+    lines.push(String::new());
+    for _ in 0..rng.range(0, 4) {
+        lines.push((0..rng.range(1, 30)).map(|_| *rng.pick(&['^', 'v', '<', '>'])).collect());
+    }
+    lines
+}
+
+impl aoc::Solution for Context {
+    fn parse(inp: Vec<String>) -> Result<Self, AocError> {
+        Context::new(inp)
+    }
+
+    fn part1(&self) -> Answer {
+        let mut context = self.clone();
+        Context::part1(&mut context).into()
+    }
+
+    fn part2(&self) -> Answer {
+        let mut context = self.clone();
+        Context::part2(&mut context).into()
+    }
+
+    // Both warehouses after the robot made all its moves
+    fn pictures(&self) -> Vec<(String, Canvas)> {
+        let mut context = self.clone();
+        Context::part1(&mut context);
+        Context::part2(&mut context);
+        let palette = Context::palette();
+        vec![
+            ("warehouse".to_owned(), Canvas::from_grid(&context.matrix, &palette)),
+            ("wide-warehouse".to_owned(), Canvas::from_grid(&context.matrix2, &palette)),
+        ]
+    }
+
+    // The robot's moves in both warehouses
+    fn animations(&self, every: usize) -> Vec<(String, Vec<Canvas>)> {
+        let palette = Context::palette();
+        let mut context = self.clone();
+        let (mut narrow, mut wide) = (Recorder::new(every), Recorder::new(every));
+        narrow.frame(Canvas::from_grid(&context.matrix, &palette));
+        wide.frame(Canvas::from_grid(&context.matrix2, &palette));
+        for i in 0..context.moves.len() {
+            Context::move_bot(&mut context.bot, &mut context.matrix, &context.moves, i);
+            narrow.step(|| Canvas::from_grid(&context.matrix, &palette));
+            Context::move_bot_2(&mut context.bot2, &mut context.matrix2, &context.moves, i);
+            wide.step(|| Canvas::from_grid(&context.matrix2, &palette));
+        }
+        vec![("warehouse".to_owned(), narrow.into_frames()), ("wide-warehouse".to_owned(), wide.into_frames())]
+    }
+
+    fn simulations(&self) -> Vec<(String, Box<dyn Simulation>)> {
+        vec![
+            ("warehouse".to_owned(), Box::new(Moves::new(self.clone(), false))),
+            ("wide-warehouse".to_owned(), Box::new(Moves::new(self.clone(), true))),
+        ]
+    }
+}
+
+#[allow(unused_imports)]
+#[cfg(test)]
+mod aoc15 {
+    use super::*;
+
+    #[test]
+    fn properties() {
+        prop::check(50, generate, |inp| {
+            let mut context = Context::new(inp).unwrap();
+            let mut still = context.clone();
+            still.moves.clear();
+            // Without moves the answers are the coordinates of the boxes as they stand
+            let boxes: Vec<(usize, usize)> = context.matrix.find_all(&Type::Box).collect();
+            assert_eq!(still.part1(), boxes.iter().map(|(r, c)| 100 * r + c).sum());
+            assert_eq!(still.part2(), boxes.iter().map(|(r, c)| 100 * r + 2 * c).sum());
+            // Pushing never creates, destroys or splits a box, nor moves a wall
+            let walls: Vec<(usize, usize)> = context.matrix2.find_all(&Type::Block).collect();
+            context.part1();
+            context.part2();
+            assert_eq!(context.matrix.find_all(&Type::Box).count(), boxes.len());
+            assert_eq!(context.matrix.find_all(&Type::Bot).count(), 1);
+            assert_eq!(context.matrix2.find_all(&Type::Block).collect::<Vec<_>>(), walls);
+            let halves: Vec<(usize, usize)> = context.matrix2.find_all(&Type::BoxLeft).collect();
+            assert_eq!(halves.len(), boxes.len());
+            assert!(halves.iter().all(|&(r, c)| context.matrix2[(r, c + 1)] == Type::BoxRight));
+        });
+    }
+
+    #[test]
+    fn example1() {
+        let text: Vec<String> = read_day(15, "example1").expect("couldn't read input - aoc15");;
+        let mut context = Context::new(text).unwrap();
+        //let part1 = context.part1(5, 11, 7);
+        //println!("Example part1: {}", part1);
+        let part1 = context.part1();
+        println!("Example1 Part1: {:?}", part1);
+        println!("Example1 Part2: {:?}", context.part2());
+    }
+
+    #[test]
+    fn example2() {
+        let text: Vec<String> = read_day(15, "example2").expect("couldn't read input - aoc15");
+        let mut context = Context::new(text).unwrap();
+        //println!("context: {:?}", context);
+        let part1 = context.part1();
+        println!("Example2 part1: {}", part1);
+        //let (part1, area) = context.part1();
+        //println!("Example1 Part1: {:?}", part1);
+        println!("Example2 Part2: {:?}", context.part2());
+    }
+
+    #[test]
+    fn example3() {
+        let text: Vec<String> = read_day(15, "example3").expect("couldn't read input - aoc15");;
+        let mut context = Context::new(text).unwrap();
+        let part1 = context.part1();
+        println!("Example3 part1: {}", part1);
+        //let part1 = context.part1();
+        //println!("Example3 Part1: {:?}", part1);
+        println!("Example3 Part2: {:?}", context.part2());
+    }
+
+   #[test]
+    fn actual() {
+        let text: Vec<String> = read_day(15, "input").expect("couldn't read input - aoc15");
+        let mut context = Context::new(text).unwrap();
+        let part1 = bench(|| context.part1(), Some("part1"));
+        println!("Part1: {:?}", part1);
+        println!("Part2: {:?}", bench(|| context.part2(), Some("part2")));
+    }
+}
\ No newline at end of file
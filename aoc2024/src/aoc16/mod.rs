@@ -0,0 +1,268 @@
+use crate::read_day;
+use aoc::utils::bench;
+use aoc::utils::prop;
+use aoc::utils::rng::Rng;
+use aoc::{Answer, Implementation};
+use aoc::error::AocError;
+use aoc::utils::grid::Grid;
+use aoc::utils::geometry::{Dir, Point};
+use aoc::utils::search::{astar, bfs, dijkstra, Search};
+use aoc::utils::render::{Canvas, Palette, GREEN, GREY, ORANGE, RED, WHITE};
+use core::num;
+use std::fmt::{self, write, Debug, Formatter};
+use std::collections::{HashSet, VecDeque};
+use std::hash::Hash;
+use std::ops::Index;
+use std::usize;
+use rayon::prelude::*;
+
+type Pos = Point;
+
+#[derive(Copy, Clone, PartialEq)]
+enum Type {
+    Block,
+    Empty
+}
+
+impl Debug for Type {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Type::Block => write!(f, "#"),
+            Type::Empty => write!(f, "."),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Context {
+    start: Pos,
+    end: Pos,
+    matrix: Grid<Type>,
+}
+
+impl Context {
+    pub fn new(inp: Vec<String>) -> Result<Context, AocError> {
+        let mut start = Point::new(-1, -1);
+        let mut end = Point::new(-1, -1);
+        let matrix = Grid::parse(&inp, |(k, j), c| match c {
+            '#' => Ok(Type::Block),
+            'S' => {
+                start = Point::from_index((k, j));
+                Ok(Type::Empty)
+            },
+            'E' => {
+                end = Point::from_index((k, j));
+                Ok(Type::Empty)
+            },
+            '.' => Ok(Type::Empty),
+            _ => Err(AocError::unexpected_char(k, j, c)),
+        })?;
+        if start == Point::new(-1, -1) {
+            return Err(AocError::missing("start 'S'"));
+        }
+        if end == Point::new(-1, -1) {
+            return Err(AocError::missing("end 'E'"));
+        }
+        Ok(Context {
+            start: start,
+            end: end,
+            matrix: matrix,
+        })
+    }
+
+    pub fn print_matrix(&self) {
+        //println!("start: ({}, {})", self.start.0, self.start.1);
+        //println!("end: ({}, {})", self.end.0, self.end.1);
+        println!("{:?}", self.matrix);
+    }
+
+    // Stepping forward costs 1, turning left or right and then stepping 1001
+    fn moves(&self, &(pos, m): &(Pos, Dir)) -> Vec<((Pos, Dir), usize)> {
+        [(m, 1), (m.turn_right(), 1001), (m.turn_left(), 1001)].into_iter()
+            .filter(|&(d, _)| self.not_block(&(pos + d)))
+            .map(|(d, c)| ((pos + d, d), c))
+            .collect()
+    }
+
+    fn search(&self) -> Search<(Pos, Dir)> {
+        dijkstra([(self.start, Dir::E)], |s| self.moves(s), |&(pos, _)| pos == self.end)
+    }
+
+    // Lowest score, `None` if the end can't be reached
+    pub fn part1(&self) -> (Option<usize>, Grid<usize>) {
+        let search = self.search();
+        let mut cost = Grid::new(self.matrix.rows(), self.matrix.cols(), usize::MAX);
+        for (&(pos, _), &c) in &search.dist {
+            cost[pos] = cost[pos].min(c);
+        }
+        (Some(cost[self.end]).filter(|&c| c != usize::MAX), cost)
+    }
+
+    // Part 1 as first written, a breadth first walk that lowers the cost of a tile whenever it
+    // reaches it more cheaply
+    fn part1_bfs(&self) -> Option<usize> {
+        let mut cost = Grid::new(self.matrix.rows(), self.matrix.cols(), usize::MAX);
+        let mut q = VecDeque::new();
+        q.push_back((self.start, Dir::E, 0));
+        while !q.is_empty() {
+            let (pos, m, c) = q.pop_front().unwrap();
+            if cost[pos] > c {
+                cost[pos] = c;
+            } else {
+                continue;
+            }
+            if pos == self.end {
+                continue;
+            }
+            for ((n, d), step) in self.moves(&(pos, m)) {
+                q.push_back((n, d, c + step));
+            }
+        }
+        Some(cost[self.end]).filter(|&c| c != usize::MAX)
+    }
+
+    // Part 1 with A*, guided by the distance left to the end, and without the cost grid part 2
+    // needs
+    fn part1_astar(&self) -> Option<usize> {
+        astar([(self.start, Dir::E)], |s| self.moves(s), |&(pos, _)| pos.manhattan(self.end), |&(pos, _)| pos == self.end)
+            .goal_cost()
+    }
+
+    fn not_block(&self, x: &Pos) -> bool { 
+        self.matrix.at((*x).into()) == Some(&Type::Empty)
+    }
+
+    fn best_tiles(&self, cost1: &Grid<usize>) -> HashSet<Pos> {
+        let search = self.search();
+        let best = cost1[self.end];
+        let ends: Vec<(Pos, Dir)> = Dir::ORTHOGONAL.iter()
+            .map(|&d| (self.end, d))
+            .filter(|s| search.cost(s) == Some(best))
+            .collect();
+        search.on_optimal_paths(&ends).into_iter().map(|(pos, _)| pos).collect()
+    }
+
+    // Tiles on any of the cheapest paths, `None` if there are none
+    pub fn part2(&self, cost1: &Grid<usize>) -> Option<usize> {
+        if cost1[self.end] == usize::MAX {
+            return None;
+        }
+        Some(self.best_tiles(cost1).len())
+    }
+}
+
+// Walled maze with the start in the bottom left and the end in the top right corner, always
+// connected
+pub fn generate(rng: &mut Rng) -> Vec<String> {
+    loop {
+        let (rows, cols) = (rng.range(4, 12) as usize, rng.range(4, 12) as usize);
+        let density = rng.range(0, 35) as f64 / 100.0;
+        let mut maze: Vec<Vec<char>> = (0..rows)
+            .map(|r| (0..cols)
+                .map(|c| if r == 0 || c == 0 || r == rows - 1 || c == cols - 1 || rng.chance(density) { '#' } else { '.' })
+                .collect())
+            .collect();
+        let (start, end) = ((rows - 2, 1), (1, cols - 2));
+        maze[start.0][start.1] = 'S';
+        maze[end.0][end.1] = 'E';
+        let walls = Grid::from_vec(rows, cols, maze.iter().flatten().map(|&c| c == '#').collect());
+        if bfs([start], |&p| walls.neighbours4(p).filter(|&n| !walls[n]).collect::<Vec<_>>(), |&p| p == end).goal.is_some() {
+            return maze.iter().map(|row| row.iter().collect()).collect();
+        }
+    }
+}
+
+impl aoc::Solution for Context {
+    fn parse(inp: Vec<String>) -> Result<Self, AocError> {
+        Context::new(inp)
+    }
+
+    fn part1(&self) -> Answer {
+        Context::part1(self).0.into()
+    }
+
+    fn part2(&self) -> Answer {
+        let (_, cost) = Context::part1(self);
+        Context::part2(self, &cost).into()
+    }
+
+    fn alternatives(&self) -> Vec<Implementation<'_>> {
+        vec![
+            Implementation::new("bfs", 1, || self.part1_bfs().into()),
+            Implementation::new("astar", 1, || self.part1_astar().into()),
+        ]
+    }
+
+    // Tiles on the cheapest paths, from start in green to end in red
+    fn pictures(&self) -> Vec<(String, Canvas)> {
+        let (_, cost) = Context::part1(self);
+        let mut canvas = Canvas::from_grid(&self.matrix, &Palette::new(WHITE).with(Type::Block, GREY));
+        canvas.paint(self.best_tiles(&cost).into_iter().filter_map(|p| self.matrix.index_of(p)), ORANGE);
+        canvas.paint(self.matrix.index_of(self.start), GREEN);
+        canvas.paint(self.matrix.index_of(self.end), RED);
+        vec![("best-paths".to_owned(), canvas)]
+    }
+}
+
+#[allow(unused_imports)]
+#[cfg(test)]
+mod aoc16 {
+    use super::*;
+
+    #[test]
+    fn properties() {
+        prop::check(50, generate, |inp| {
+            let context = Context::new(inp).unwrap();
+            let (score, cost) = context.part1();
+            assert_eq!(context.part1_astar(), score);
+            assert_eq!(context.part1_bfs(), score);
+            let score = score.unwrap();
+            let distance = context.start.manhattan(context.end);
+            // The end lies up and to the right, so facing east the reindeer turns at least once,
+            // and every step off the straight line has to be walked back
+            let (turns, steps) = (score / 1000, score % 1000);
+            assert!(turns >= 1 && steps >= distance && (steps - distance) % 2 == 0);
+            let tiles = context.part2(&cost).unwrap();
+            assert!(tiles >= steps + 1);
+            assert!(tiles <= context.matrix.find_all(&Type::Empty).count());
+        });
+    }
+
+    #[test]
+    fn example1() {
+        let text: Vec<String> = read_day(16, "example1").expect("couldn't read input - aoc16");;
+        let context = Context::new(text).unwrap();
+        context.print_matrix();
+        let (part1, cost) = context.part1();
+        println!("Example1 Part1: {:?}", part1);
+        println!("Example1 Part2: {:?}", context.part2(&cost));
+    }
+
+    #[test]
+    fn example2() {
+        let text: Vec<String> = read_day(16, "example2").expect("couldn't read input - aoc16");
+        let mut context = Context::new(text).unwrap();
+        //println!("context: {:?}", context);
+        let (part1, cost) = context.part1();
+        println!("Example2 part1: {:?}", part1);
+        //let (part1, area) = context.part1();
+        //println!("Example1 Part1: {:?}", part1);
+        println!("Example2 Part2: {:?}", context.part2(&cost));
+    }
+
+    #[test]
+    fn unreachable() {
+        let context = Context::new(vec!["#####".to_owned(), "#S#E#".to_owned(), "#####".to_owned()]).unwrap();
+        let (part1, cost) = context.part1();
+        assert_eq!((part1, context.part1_astar(), context.part1_bfs(), context.part2(&cost)), (None, None, None, None));
+    }
+
+   #[test]
+    fn actual() {
+        let text: Vec<String> = read_day(16, "input").expect("couldn't read input - aoc16");
+        let mut context = Context::new(text).unwrap();
+        let (part1, cost) = bench(|| context.part1(), Some("part1"));
+        println!("Part1: {:?}", part1);
+        println!("Part2: {:?}", bench(|| context.part2(&cost), Some("part2")));
+    }
+}
\ No newline at end of file
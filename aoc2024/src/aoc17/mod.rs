@@ -0,0 +1,324 @@
+use crate::read_day;
+use aoc::utils::bench;
+use aoc::utils::prop;
+use aoc::utils::rng::Rng;
+use aoc::{Answer, Implementation};
+use aoc::error::{AocError, parse_token};
+use core::num;
+use std::fmt::{write, Debug, Formatter};
+use std::cmp::Ordering;
+use std::collections::{VecDeque, HashMap, HashSet, BinaryHeap};
+use std::hash::Hash;
+use std::ops::Index;
+use std::usize;
+use rayon::prelude::*;
+
+type Num = u64;
+type NumInd = usize;
+
+// A program can loop forever, e.g. `3,0` with A != 0
+const MAX_STEPS: usize = 1_000_000;
+
+// Opcodes whose operand is a combo operand
+const COMBO: [Num; 5] = [0, 2, 5, 6, 7];
+
+#[derive(Debug, Clone)]
+pub struct Context {
+    registers: Vec<Num>,
+    instructions: Vec<Num>
+}
+
+impl Context {
+    pub fn new(registers: Vec<Num>, instructions: Vec<Num>) -> Context {
+        Context {
+            registers: registers,
+            instructions: instructions
+        }
+    }
+
+    // Input is three "Register X: n" lines, a blank line and "Program: a,b,c,..."
+    pub fn from_input(inp: Vec<String>) -> Result<Context, AocError> {
+        let mut registers = vec![];
+        let mut instructions = vec![];
+        for (i, line) in inp.iter().enumerate().filter(|(_, l)| !l.trim().is_empty()) {
+            let (key, val) = line.split_once(':').ok_or(AocError::malformed(i, "expected `Register X: n` or `Program: ...`"))?;
+            if key.starts_with("Register") {
+                registers.push(parse_token::<Num>(i, line, val)?);
+            } else if key == "Program" {
+                instructions = val.split(',').map(|v| parse_token::<Num>(i, line, v)).collect::<Result<_, AocError>>()?;
+                if let Some(j) = instructions.iter().position(|&v| v > 7) {
+                    return Err(AocError::malformed(i, &format!("program value {} is not a 3-bit number", instructions[j])));
+                }
+                // adv, bst, out, bdv and cdv take a combo operand, for which 7 is reserved
+                if let Some(k) = (0..instructions.len().saturating_sub(1)).step_by(2).find(|&k| COMBO.contains(&instructions[k]) && instructions[k + 1] == 7) {
+                    return Err(AocError::malformed(i, &format!("instruction {} has the reserved combo operand 7", k / 2)));
+                }
+            } else {
+                return Err(AocError::malformed(i, "expected `Register X: n` or `Program: ...`"));
+            }
+        }
+        if registers.len() != 3 {
+            return Err(AocError::missing("registers A, B and C"));
+        }
+        if instructions.is_empty() {
+            return Err(AocError::missing("program"));
+        }
+        if instructions.len() % 2 == 1 {
+            return Err(AocError::missing("operand of the last instruction"));
+        }
+        Ok(Context::new(registers, instructions))
+    }
+
+    fn get_operand(&self, operand_code: &Num) -> Num {
+        if *operand_code < 4 {
+            return *operand_code;
+        }
+        if *operand_code < 7 {
+            return self.registers[(*operand_code - 4) as NumInd];
+        }
+        unreachable!("combo operand 7 is rejected when parsing");
+    }
+
+    // Output of the program joined by commas, `None` if it doesn't halt
+    pub fn part1(&mut self) -> Option<String> {
+        let out = self.run()?;
+        Some(out.iter().map(|v| v.to_string()).collect::<Vec<String>>().join(","))
+    }
+
+    // Smallest A for which the program outputs `target`, or `None`. Each candidate is run on the VM.
+    pub fn lowest_a(&self, target: &[Num]) -> Option<Num> {
+        self.lowest_a_by(target, |a, rest| {
+            let mut context = Context::new(vec![a, self.registers[1], self.registers[2]], self.instructions.clone());
+            context.run().map_or(false, |out| out == rest)
+        })
+    }
+
+    // Same search using `compute_expression` instead of the VM, only for programs of the puzzle's shape
+    fn lowest_a_formula(&self, target: &[Num]) -> Option<Num> {
+        let keys = self.formula_keys()?;
+        self.lowest_a_by(target, |a, rest| self.compute_expression(keys, a) == rest[0])
+    }
+
+    // Builds A three bits at a time, from the last output back. `accepts(a, rest)` checks a
+    // candidate `a` against `rest`, the outputs it has to produce.
+    fn lowest_a_by(&self, target: &[Num], accepts: impl Fn(Num, &[Num]) -> bool) -> Option<Num> {
+        let cur_a = 0;
+        let mut q = VecDeque::new();
+        let mut v = target.to_vec();
+        v.reverse();
+        q.push_back((cur_a, 0));
+
+        let mut min_val = None;
+
+        while !q.is_empty() {
+            let (cur, ind) = q.pop_front().unwrap();
+            if ind == v.len() {
+                min_val = Some(min_val.map_or(cur, |m: Num| m.min(cur)));
+                continue;
+            }
+            let start = 8 * cur;
+            let end = start + 8;
+            for j in start..end {
+                // A leading zero digit would end the program one output early
+                if j == 0 && ind + 1 < v.len() {
+                    continue;
+                }
+                if accepts(j, &target[target.len() - 1 - ind..]) {
+                    q.push_back((j, ind + 1));
+                }
+            }
+        }
+        min_val
+    }
+
+    // The two `bxl` operands of a program `2,4,1,k1,7,5,1,k2,0,3,4,_,5,5,3,0`, the shape of the
+    // puzzle's input that `compute_expression` was worked out for
+    fn formula_keys(&self) -> Option<(Num, Num)> {
+        let shape = [2, 4, 1, 0, 7, 5, 1, 0, 0, 3, 4, 0, 5, 5, 3, 0];
+        let matches = self.instructions.len() == shape.len()
+            && self.instructions.iter().zip(shape).enumerate().all(|(k, (&v, s))| k == 3 || k == 7 || k == 11 || v == s);
+        matches.then(|| (self.instructions[3], self.instructions[7]))
+    }
+
+    // The value such a program outputs for A before shifting it: (((A%8)^k1^k2) ^ (A/2**((A%8)^k1))) % 8
+    fn compute_expression(&self, (k1, k2): (Num, Num), A: Num) -> Num {
+        // B after the two `bxl`s
+        let first_xor = (A % 8) ^ k1 ^ k2;
+    
+        // C is A shifted by B after the first `bxl`
+        let inner_xor = (A % 8) ^ k1;
+        let second_xor = A / ((2 as Num).pow(inner_xor as u32));
+    
+        // Perform the second XOR operation
+        let result = first_xor ^ second_xor;
+    
+        // Apply the final modulus operation
+        let final_result = result % 8;
+    
+        final_result
+    }
+
+    // A divided by 2 to the power of the combo operand at `operand_ind`, 0 once every bit is
+    // shifted out
+    fn shifted_a(&self, operand_ind: NumInd) -> Num {
+        let shift = u32::try_from(self.get_operand(&self.instructions[operand_ind])).unwrap_or(u32::MAX);
+        self.registers[0].checked_shr(shift).unwrap_or(0)
+    }
+
+    // The program's output, `None` if it is still running after MAX_STEPS instructions
+    pub fn run(&mut self) -> Option<Vec<Num>> {
+        let mut out = vec![];
+        let mut i_point: NumInd = 0;
+        let mut counter = 0;
+        // A jump to the last index leaves an opcode without its operand, which halts too
+        while i_point + 1 < self.instructions.len() {
+            if counter >= MAX_STEPS {
+                return None;
+            }
+            let operation = self.instructions[i_point];
+            let operand_ind = i_point + 1;
+            let mut incr_by_2 = true;
+            match operation {
+                0 => {
+                    self.registers[0] = self.shifted_a(operand_ind);
+                },
+                1 => {
+                    let n1 = self.registers[1];
+                    let n2 = self.instructions[operand_ind];
+                    self.registers[1] = n1 ^ n2;
+                },
+                2 => {
+                    let op = self.get_operand(&self.instructions[operand_ind]);
+                    self.registers[1] = op % 8;
+                },
+                3 => {
+                    if self.registers[0] != 0 {
+                        i_point = self.instructions[operand_ind] as NumInd;
+                        incr_by_2 = false;
+                    }
+                },
+                4 => {
+                    let n1 = self.registers[1];
+                    let n2 = self.registers[2];
+                    self.registers[1] = n1 ^ n2;
+                },
+                5 => {
+                    let o = self.get_operand(&self.instructions[operand_ind]);
+                    out.push(o % 8);
+                },
+                6 => {
+                    self.registers[1] = self.shifted_a(operand_ind);
+                },
+                7 => {
+                    self.registers[2] = self.shifted_a(operand_ind);
+                },
+                _ => panic!("Unknown instruction"),
+            };
+            if incr_by_2 {
+                i_point += 2;
+            }
+            counter += 1;
+        }
+        Some(out)
+    }
+}
+
+// The puzzle's program shape with random XOR constants and a random initial A
+pub fn generate(rng: &mut Rng) -> Vec<String> {
+    let (k1, k2) = if rng.chance(0.5) { (5, 6) } else { (rng.range(0, 7), rng.range(0, 7)) };
+    vec![
+        format!("Register A: {}", rng.range(1, 8i64.pow(10))),
+        "Register B: 0".to_owned(),
+        "Register C: 0".to_owned(),
+        String::new(),
+        format!("Program: 2,4,1,{},7,5,1,{},0,3,4,{},5,5,3,0", k1, k2, rng.range(0, 7)),
+    ]
+}
+
+impl aoc::Solution for Context {
+    fn parse(inp: Vec<String>) -> Result<Self, AocError> {
+        Context::from_input(inp)
+    }
+
+    fn part1(&self) -> Answer {
+        let mut context = self.clone();
+        Context::part1(&mut context).into()
+    }
+
+    fn part2(&self) -> Answer {
+        self.lowest_a(&self.instructions).into()
+    }
+
+    fn alternatives(&self) -> Vec<Implementation<'_>> {
+        match self.formula_keys() {
+            Some(_) => vec![Implementation::new("formula", 2, || self.lowest_a_formula(&self.instructions).into())],
+            None => vec![],
+        }
+    }
+}
+
+#[allow(unused_imports)]
+#[cfg(test)]
+mod aoc17 {
+    use super::*;
+
+    #[test]
+    fn properties() {
+        prop::check(50, generate, |inp| {
+            let context = Context::from_input(inp).unwrap();
+            let a = context.registers[0];
+            let out: Vec<Num> = context.clone().part1().unwrap().split(',').map(|v| v.parse().unwrap()).collect();
+            // One 3-bit value per octal digit of A
+            let digits = (0..).take_while(|k| a >> (3 * k) > 0).count();
+            assert_eq!(out.len(), digits);
+            assert!(out.iter().all(|&v| v < 8));
+            // Programs of the puzzle's shape
+            if let Some(keys) = context.formula_keys() {
+                assert!(out.iter().enumerate().all(|(k, &v)| v == context.compute_expression(keys, a >> (3 * k))));
+                let program = context.instructions.clone();
+                assert_eq!(context.lowest_a_formula(&program), context.lowest_a(&program));
+            }
+        });
+    }
+
+    #[test]
+    fn example1() {
+        let mut context = Context::new(vec![729, 0, 0], vec![0,1,5,4,3,0]);
+        assert_eq!(context.part1(), Some("4,6,3,5,6,3,5,2,1,0".to_owned()));
+    }
+
+    #[test]
+    fn example2() {
+        let context = Context::new(vec![2024, 0, 0], vec![0,3,5,4,3,0]);
+        assert_eq!(context.lowest_a(&context.instructions), Some(117440));
+        // Not of the puzzle's shape, so there is no formula for it
+        assert_eq!(context.formula_keys(), None);
+        assert!(aoc::Solution::alternatives(&context).is_empty());
+    }
+
+    #[test]
+    fn never_halts() {
+        let mut context = Context::new(vec![1, 0, 0], vec![3, 0]);
+        assert_eq!(context.part1(), None);
+        assert_eq!(context.lowest_a(&context.instructions.clone()), None);
+    }
+
+   #[test]
+    fn actual() {
+        //let text: Vec<String> = read_day(17, "input").expect("couldn't read input - aoc17");
+        let mut context = Context::new(vec![44348299, 0, 0], vec![2,4,1,5,7,5,1,6,0,3,4,2,5,5,3,0]);
+        let part1 = bench(|| context.part1(), Some("part1"));
+        println!("Part1: {:?}", part1);
+        // (((A%8)^3) ^ (A/2**((A%8)^5))) % 8 -> Analytical equation that program computes
+        // The value of A keeps decreasing until it reaches 0. At each step, the %8 basically means that the last 3 bits are removed.
+        // The insight is to compute this expression in reverse starting from the last value output (0) to the first value(2) 
+        // So going in the opposite direction, we add back 3 bits i.e left shift
+        // But the value of the 3 bits could be anything - (from 0 to 7)
+        // So say at step 0, we check from 0 to 7 and find that A=3 provides 0 as the answer.
+        // For step 1, we need to consider all numbers from 3 * 8 to 3 * 8 + 7. Note that there could be multiple possibilities in each step
+        // So we do a DFS/BFS until we reach the end of the instruction list.
+
+        let program = context.instructions.clone();
+        println!("Part2: {:?}", bench(|| context.lowest_a(&program), Some("part2")));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,193 @@
+use crate::read_day;
+use aoc::utils::bench;
+use aoc::utils::prop;
+use aoc::utils::rng::Rng;
+use aoc::Answer;
+use aoc::error::{AocError, parse_token};
+use aoc::utils::grid::Grid;
+use aoc::utils::search::{bfs, Search};
+use aoc::utils::render::{Canvas, GREY, ORANGE, RED, WHITE};
+use core::num;
+use std::fmt::{write, Debug, Formatter};
+use std::hash::Hash;
+use std::ops::Index;
+use std::usize;
+use rayon::prelude::*;
+
+type Num = usize;
+
+type Pos = (Num, Num);
+
+// Side of the memory space and bytes fallen for part 1 in the puzzle input. The example's are 7
+// and 12.
+const MEMORY: (usize, usize) = (71, 1024);
+
+#[derive(Debug)]
+pub struct Context {
+    bytes: Vec<Pos>,
+    r: usize,
+    c: usize,
+    fallen: usize
+}
+
+impl Context {
+    // Bytes falling into an `r`x`c` memory space, `fallen` of them by part 1
+    pub fn new(inp: Vec<String>, r: usize, c: usize, fallen: usize) -> Result<Context, AocError> {
+        let val: Vec<Pos> = Context::parse_bytes(&inp)?;
+        if let Some(k) = val.iter().position(|p| p.0 >= r || p.1 >= c) {
+            return Err(AocError::malformed(k, &format!("byte lies outside the {}x{} grid", r, c)));
+        }
+        Ok(Context {
+            bytes: val,
+            r: r,
+            c: c,
+            fallen: fallen
+        })
+    }
+
+    fn parse_bytes(inp: &Vec<String>) -> Result<Vec<Pos>, AocError> {
+        inp.iter().enumerate().map(|(i, s)| {
+            let tmp: Vec<Num> = s.trim().split(",").map(|f| parse_token::<Num>(i, s, f)).collect::<Result<_, AocError>>()?;
+            if tmp.len() != 2 {
+                return Err(AocError::malformed(i, "expected `x,y`"));
+            }
+            Ok((tmp[0], tmp[1]))
+        }).collect()
+    }
+
+    // All bytes if fewer than `upto_len` fall
+    fn corrupted(&self, upto_len: usize) -> Grid<bool> {
+        let mut corrupted: Grid<bool> = Grid::new(self.r, self.c, false);
+        for k in 0..upto_len.min(self.bytes.len()) {
+            corrupted[self.bytes[k]] = true;
+        }
+        corrupted
+    }
+
+    fn search(&self, corrupted: &Grid<bool>) -> Search<Pos> {
+        let exit = (self.r - 1, self.c - 1);
+        // A byte on the start cell blocks it like any other
+        let start = Some((0, 0)).filter(|&p| !corrupted[p]);
+        bfs(start, |&p| corrupted.neighbours4(p).filter(|&n| !corrupted[n]).collect::<Vec<Pos>>(), |&p| p == exit)
+    }
+
+    // Fewest steps from the top left to the bottom right corner, `None` if the exit is cut off
+    pub fn part1(&self, upto_len: usize) -> Option<usize> {
+        self.search(&self.corrupted(upto_len)).goal_cost()
+    }
+
+    // Corrupted memory after `upto_len` bytes with a shortest way out. Bytes are `x,y`, so
+    // rows of the picture are the second coordinate.
+    fn picture(&self, upto_len: usize) -> Canvas {
+        let corrupted = self.corrupted(upto_len);
+        let mut canvas = Canvas::new(self.c, self.r, WHITE);
+        canvas.paint(corrupted.find_all(&true).map(|(x, y)| (y, x)), GREY);
+        let search = self.search(&corrupted);
+        if let Some(path) = search.goal.and_then(|exit| search.path(&exit)) {
+            canvas.paint(path.into_iter().map(|(x, y)| (y, x)), ORANGE);
+        }
+        canvas
+    }
+
+    // Index and position of the first byte that cuts off the exit, `None` if the exit stays
+    // reachable
+    pub fn part2(&self) -> Option<(Num, Pos)> {
+        if self.part1(self.bytes.len()).is_some() {
+            return None;
+        }
+        // Smallest number of fallen bytes that blocks the way
+        let mut s = 0;
+        let mut e = self.bytes.len();
+
+        while s < e {
+            let m = s + (e - s) / 2;
+            if self.part1(m).is_none() {
+                e = m;
+            } else {
+                s = m + 1;
+            }
+        }
+        Some((s - 1, self.bytes[s - 1]))
+    }
+}
+
+// Every cell of the 7x7 example memory space except the corners, falling in random order
+pub fn generate(rng: &mut Rng) -> Vec<String> {
+    let mut bytes: Vec<Pos> = (0..7).flat_map(|x| (0..7).map(move |y| (x, y))).filter(|&p| p != (0, 0) && p != (6, 6)).collect();
+    rng.shuffle(&mut bytes);
+    bytes.iter().map(|(x, y)| format!("{},{}", x, y)).collect()
+}
+
+impl aoc::Solution for Context {
+    fn parse(inp: Vec<String>) -> Result<Self, AocError> {
+        Context::new(inp, MEMORY.0, MEMORY.0, MEMORY.1)
+    }
+
+    fn part1(&self) -> Answer {
+        Context::part1(self, self.fallen).into()
+    }
+
+    fn part2(&self) -> Answer {
+        Context::part2(self).map(|(_, pos)| pos).into()
+    }
+
+    // The way out after part 1's bytes, and the byte that cuts it off in red
+    fn pictures(&self) -> Vec<(String, Canvas)> {
+        let mut pictures = vec![("memory".to_owned(), self.picture(self.fallen))];
+        if let Some((k, (x, y))) = Context::part2(self) {
+            let mut cut_off = self.picture(k + 1);
+            cut_off.paint([(y, x)], RED);
+            pictures.push(("cut-off".to_owned(), cut_off));
+        }
+        pictures
+    }
+}
+
+#[allow(unused_imports)]
+#[cfg(test)]
+mod aoc18 {
+    use super::*;
+
+    #[test]
+    fn properties() {
+        prop::check(50, generate, |inp| {
+            let context = Context::new(inp, 7, 7, 12).unwrap();
+            // More fallen bytes never make the way shorter
+            let steps: Vec<usize> = (0..=context.bytes.len()).map(|k| context.part1(k).unwrap_or(usize::MAX)).collect();
+            assert_eq!(steps[0], 12);
+            assert!(steps.windows(2).all(|w| w[0] <= w[1]));
+            assert_eq!(*steps.last().unwrap(), usize::MAX);
+            // The reported byte is the first to cut off the exit
+            let (k, pos) = context.part2().unwrap();
+            assert_eq!(pos, context.bytes[k]);
+            assert!(steps[k] != usize::MAX && steps[k + 1] == usize::MAX, "byte {} of {:?}", k, steps);
+        });
+    }
+
+    #[test]
+    fn cut_off() {
+        // The start itself is corrupted, then the exit is walled in
+        let context = Context::new(vec!["0,0".to_owned()], 3, 3, 1).unwrap();
+        assert_eq!((context.part1(0), context.part1(1)), (Some(4), None));
+        let context = Context::new(vec!["2,1".to_owned(), "1,2".to_owned()], 3, 3, 2).unwrap();
+        assert_eq!(context.part1(2), None);
+        assert!(matches!(aoc::Solution::part1(&context), Answer::Unsolved));
+    }
+
+    #[test]
+    fn example1() {
+        let text: Vec<String> = read_day(18, "example").expect("couldn't read input - aoc18");
+        let context = Context::new(text, 7, 7, 12).unwrap();
+        assert_eq!(context.part1(context.fallen), Some(22));
+        assert_eq!(context.part2().map(|(_, pos)| pos), Some((6, 1)));
+    }
+
+   #[test]
+    fn actual() {
+        let text: Vec<String> = read_day(18, "input").expect("couldn't read input - aoc18");
+        let context = Context::new(text, MEMORY.0, MEMORY.0, MEMORY.1).unwrap();
+        let part1 = bench(|| context.part1(2911), Some("part1"));
+        println!("Part1: {:?}", part1);
+        println!("Part2: {:?}", bench(|| context.part2(), Some("part2")));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,158 @@
+use crate::read_day;
+use aoc::utils::bench;
+use aoc::utils::prop;
+use aoc::utils::rng::Rng;
+use aoc::Answer;
+use aoc::error::{AocError, parse_token};
+
+#[derive(Debug)]
+pub struct Context {
+    levels: Vec<Vec<i32>>
+}
+
+impl Context {
+    pub fn new(inp: Vec<String>) -> Result<Context, AocError> {
+        if inp.is_empty() {
+            return Err(AocError::EmptyInput);
+        }
+        let levels: Vec<Vec<i32>> = inp.iter()
+            .enumerate()
+            .map(|(i, s)| s.split_whitespace()
+                .map(|t| parse_token::<i32>(i, s, t))
+                .collect::<Result<Vec<i32>, AocError>>())
+            .collect::<Result<_, AocError>>()?;
+        if let Some(i) = levels.iter().position(|l| l.len() < 2) {
+            return Err(AocError::malformed(i, "a report needs at least two levels"));
+        }
+        Ok(Context {
+            levels: levels
+        })
+    }
+
+    fn check_safety(l: &Vec<i32>, list_len: usize) -> (bool, usize, usize) {
+        // A single level, left after removing one from a report of two, is safe
+        if l.len() < 2 {
+            return (true, usize::MAX, usize::MAX);
+        }
+        let inc: bool = l[1] - l[0] > 0;
+        for i in 0..(l.len() - 1) {
+            let diff = l[i + 1] - l[i];
+            let ord = diff > 0;
+            let abs_diff = diff.wrapping_abs();
+            if inc ^ ord || abs_diff < 1 || abs_diff > 3 {
+                return (false, i, i + 1);
+            }
+        }
+        (true, usize::MAX, usize::MAX)
+    }
+
+    pub fn part1(&self) -> usize {
+        let list_len = self.levels[0].len();
+        self.levels.iter()
+            .map(|l| Context::check_safety(l, list_len))
+            .filter(|&x| x.0)
+            .count()
+    }
+
+    fn remove(vec: &Vec<i32>, index: usize) -> Vec<i32> {
+        let mut first = match index == 0 {
+            true => vec![],
+            false => vec[0..index].to_vec()
+        };
+        let second = match index == vec.len() - 1 {
+            true => vec![],
+            false => vec[(index+1)..].to_vec()
+        };
+        first.extend(second);
+        first
+    }
+
+    pub fn part2(&self) -> usize {
+        let list_len = self.levels[0].len();
+        self.levels.iter()
+            .map(|l| {
+                let part1 = Context::check_safety(l, list_len);
+                if part1.0 {
+                    return true;
+                }
+                for i in 0..l.len() {
+                    let option = Context::remove(l, i);
+                    if Context::check_safety(&option, list_len).0 {
+                        return true;
+                    }
+                }
+                false
+            })
+            .filter(|&x| x)
+            .count()
+    }
+}
+
+// Reports of 5 to 8 levels: steadily rising or falling ones, some with one level broken
+pub fn generate(rng: &mut Rng) -> Vec<String> {
+    (0..rng.range(1, 30))
+        .map(|_| {
+            let len = rng.range(5, 8) as usize;
+            let sign = if rng.chance(0.5) { 1 } else { -1 };
+            let mut levels = vec![rng.range(10, 80)];
+            while levels.len() < len {
+                levels.push(levels.last().unwrap() + sign * rng.range(1, 3));
+            }
+            if rng.chance(0.5) {
+                let k = rng.below(len);
+                levels[k] = rng.range(1, 99);
+            }
+            levels.iter().map(|l| l.to_string()).collect::<Vec<String>>().join(" ")
+        })
+        .collect()
+}
+
+impl aoc::Solution for Context {
+    fn parse(inp: Vec<String>) -> Result<Self, AocError> {
+        Context::new(inp)
+    }
+
+    fn part1(&self) -> Answer {
+        Context::part1(self).into()
+    }
+
+    fn part2(&self) -> Answer {
+        Context::part2(self).into()
+    }
+}
+
+#[cfg(test)]
+mod aoc2 {
+    use super::*;
+
+    #[test]
+    fn properties() {
+        prop::check(50, generate, |inp| {
+            let reversed: Vec<String> = inp.iter()
+                .map(|l| l.split_whitespace().rev().collect::<Vec<&str>>().join(" "))
+                .collect();
+            let context = Context::new(inp).unwrap();
+            let (part1, part2) = (context.part1(), context.part2());
+            // Dampening only adds safe reports, and safety doesn't depend on the direction
+            assert!(part1 <= part2 && part2 <= context.levels.len());
+            let reversed = Context::new(reversed).unwrap();
+            assert_eq!((reversed.part1(), reversed.part2()), (part1, part2));
+        });
+    }
+
+    #[test]
+    fn example1() {
+        let text: Vec<String> = read_day(2, "example").expect("couldn't read input - aoc2");
+        let context = Context::new(text).unwrap();
+        println!("Part1: {:?}", context.part1());
+        println!("Part2: {:?}", context.part2());
+    }
+
+    #[test]
+    fn actual() {
+        let text: Vec<String> = read_day(2, "input").expect("couldn't read input - aoc2");
+        let context = Context::new(text).unwrap();
+        println!("Part1: {:?}", bench(|| context.part1(), Some("aoc2: part1")));
+        println!("Part2: {:?}", bench(|| context.part2(), Some("aoc2: part2")));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,139 @@
+use crate::read_day;
+use aoc::utils::bench;
+use aoc::utils::prop;
+use aoc::utils::rng::Rng;
+use aoc::Answer;
+use aoc::error::AocError;
+use regex::Regex;
+use std::cmp::PartialEq;
+
+#[derive(Debug)]
+pub struct Context {
+    memory: String
+}
+
+#[derive(Debug, PartialEq)]
+enum Condition {
+    Do,
+    Dont
+}
+
+impl Context {
+    pub fn new(inp: Vec<String>) -> Result<Context, AocError> {
+        Ok(Context {
+            memory: inp.join("")
+        })
+    }
+
+    pub fn part1(&self) -> u32 {
+        let mut sum = 0;
+        let re = Regex::new(r"mul\(\d{1,3}\,\d{1,3}\)").unwrap();
+        for cap in re.find_iter(&self.memory) {
+            let s = &cap.as_str()[4..];
+            let s = &s[..s.len() - 1];
+            let nums: Vec<&str> = s.split(",").collect();
+            let num1 = nums[0].parse::<u32>().unwrap();
+            let num2 =  nums[1].parse::<u32>().unwrap();
+            sum += num1 * num2;
+        }
+        sum
+    }
+
+    pub fn part2(&self) -> u32 {
+        let mut sum = 0;
+        let re1 = Regex::new(r"mul\(\d{1,3}\,\d{1,3}\)").unwrap();
+        let re2 = Regex::new(r"don't\(\)|do\(\)").unwrap();
+        let conditions: Vec<(Condition, usize)> = re2.find_iter(&self.memory).map(|v| {
+            let s = v.as_str();
+            return match s {
+                "do()" => (Condition::Do, v.start()),
+                "don't()" => (Condition::Dont, v.start()),
+                _ => panic!("Unexpected input")
+            }
+        }).collect();
+        let mut cur_ind = 0;
+        for cap in re1.find_iter(&self.memory) {
+            let s = &cap.as_str()[4..];
+            let s = &s[..s.len() - 1];
+            let nums: Vec<&str> = s.split(",").collect();
+            let num1 = nums[0].parse::<u32>().unwrap();
+            let num2 =  nums[1].parse::<u32>().unwrap();
+            let start = cap.start();
+            while cur_ind < conditions.len() && conditions[cur_ind].1 < start {
+                cur_ind += 1;
+            }
+            if cur_ind == 0 || conditions[cur_ind - 1].0 == Condition::Do {
+                sum += num1 * num2;
+            }
+        }
+        sum
+    }
+}
+
+// Corrupted memory: `mul(a,b)`, near misses, `do()` and `don't()` among junk characters
+pub fn generate(rng: &mut Rng) -> Vec<String> {
+    let junk = ['x', '%', '&', '!', ' ', '@', '(', ')', ',', '[', ']', 'm', 'u', 'l'];
+    (0..rng.range(1, 4))
+        .map(|_| {
+            let mut line = String::new();
+            for _ in 0..rng.range(5, 40) {
+                match rng.below(6) {
+                    0 | 1 => line += &format!("mul({},{})", rng.range(0, 999), rng.range(0, 999)),
+                    2 => line += *rng.pick(&["mul(4*", "mul(6,9!", "?(12,34)", "mul ( 2 , 4 )", "mul(1234,5)"]),
+                    3 => line += *rng.pick(&["do()", "don't()"]),
+                    _ => line.push(*rng.pick(&junk)),
+                }
+            }
+            line
+        })
+        .collect()
+}
+
+impl aoc::Solution for Context {
+    fn parse(inp: Vec<String>) -> Result<Self, AocError> {
+        Context::new(inp)
+    }
+
+    fn part1(&self) -> Answer {
+        Context::part1(self).into()
+    }
+
+    fn part2(&self) -> Answer {
+        Context::part2(self).into()
+    }
+}
+
+#[cfg(test)]
+mod aoc3 {
+    use super::*;
+
+    #[test]
+    fn properties() {
+        prop::check(50, generate, |inp| {
+            let enabled: Vec<String> = inp.iter().map(|l| l.replace("don't()", "")).collect();
+            let context = Context::new(inp).unwrap();
+            // Conditionals only switch multiplications off, and without `don't()` nothing is
+            assert!(context.part2() <= context.part1());
+            let enabled = Context::new(enabled).unwrap();
+            assert_eq!(enabled.part2(), enabled.part1());
+        });
+    }
+
+    #[test]
+    fn example1() {
+        let text: Vec<String> = read_day(3, "example").expect("couldn't read input - aoc3");
+        let context = Context::new(text).unwrap();
+        println!("Part1: {:?}", context.part1());
+        let text: Vec<String> = read_day(3, "example2").expect("couldn't read input - aoc3");
+        let context = Context::new(text).unwrap();
+        println!("Part2: {:?}", context.part2());
+    }
+
+    #[test]
+    fn actual() {
+        let text: Vec<String> = read_day(3, "input").expect("couldn't read input - aoc3");
+        let context = Context::new(text).unwrap();
+        println!("Part1: {:?}", bench(|| context.part1(), Some("part1")));
+        println!("Part2: {:?}", bench(|| context.part2(), Some("part2")));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,136 @@
+use crate::read_day;
+use aoc::utils::bench;
+use aoc::utils::prop;
+use aoc::utils::rng::Rng;
+use aoc::Answer;
+use aoc::error::AocError;
+use aoc::utils::grid::Grid;
+use regex::Regex;
+use std::cmp::PartialEq;
+
+#[derive(Debug)]
+pub struct Context {
+    letters: Grid<u8>,
+}
+
+const vals: &[u8] = &['X' as u8, 'M' as u8, 'A' as u8, 'S' as u8];
+const permutations: &[(isize, isize)] = &[(0,1), (0,-1), (1,0), (-1, 0), (-1,1), (-1, -1), (1, 1), (1, -1)];
+
+impl Context {
+    pub fn new(inp: Vec<String>) -> Result<Context, AocError> {
+        Ok(Context {
+            letters: Grid::parse(&inp, |_, c| Ok(c as u8))?
+        })
+    }
+
+    fn count_xmas(&self, i: isize, j: isize) -> u32 {
+        let mut sum = 8;
+        for (di, dj) in permutations {
+            for k in 0..4 {
+                let ni = i + k * di;
+                let nj = j + k * dj;
+                if self.letters.at((ni, nj)) != Some(&vals[k as usize]) {
+                    sum -= 1;
+                    break;
+                }
+            }
+        }
+        sum
+    }
+
+    pub fn part1(&self) -> u32 {
+        let mut sum = 0;
+        for (i, j) in self.letters.positions() {
+            //let c = self.count_xmas(i as isize, j as isize);
+            //println!("i: {}, j: {}, count: {}", i, j, c);
+            sum += self.count_xmas(i as isize, j as isize);
+        }
+        sum
+    }
+
+    pub fn part2(&self) -> u32 {
+        let A = 'A' as u8;
+        let cp1 = (vals[1], vals[3]);
+        let cp2 = (vals[3], vals[1]);
+
+        let mut sum = 0;
+        for (i, j) in self.letters.find_all(&A) {
+            if i == 0 || j == 0 || i + 1 >= self.letters.rows() || j + 1 >= self.letters.cols() {
+                continue;
+            }
+            let pair1 = (self.letters[(i + 1, j - 1)], self.letters[(i - 1, j + 1)]);
+            let pair2 = (self.letters[(i - 1, j - 1)], self.letters[(i + 1, j + 1)]);
+            if (pair1 == cp1 || pair1 == cp2) && (pair2 == cp1 || pair2 == cp2) {
+                sum += 1;
+            }
+        }
+        sum
+    }
+}
+
+// Word search of X, M, A and S, with some XMAS and X-MAS planted in random places
+pub fn generate(rng: &mut Rng) -> Vec<String> {
+    let (rows, cols) = (rng.range(3, 12) as usize, rng.range(3, 12) as usize);
+    let mut grid: Vec<Vec<char>> = (0..rows).map(|_| (0..cols).map(|_| *rng.pick(&['X', 'M', 'A', 'S'])).collect()).collect();
+    for _ in 0..rng.range(0, 4) {
+        let (dr, dc) = *rng.pick(&aoc::utils::grid::ALL_DIRECTIONS);
+        let (r, c) = (rng.below(rows) as isize, rng.below(cols) as isize);
+        for (k, ch) in "XMAS".chars().enumerate() {
+            let (rr, cc) = (r + dr * k as isize, c + dc * k as isize);
+            if rr >= 0 && cc >= 0 && (rr as usize) < rows && (cc as usize) < cols {
+                grid[rr as usize][cc as usize] = ch;
+            }
+        }
+    }
+    grid.iter().map(|row| row.iter().collect()).collect()
+}
+
+impl aoc::Solution for Context {
+    fn parse(inp: Vec<String>) -> Result<Self, AocError> {
+        Context::new(inp)
+    }
+
+    fn part1(&self) -> Answer {
+        Context::part1(self).into()
+    }
+
+    fn part2(&self) -> Answer {
+        Context::part2(self).into()
+    }
+}
+
+#[cfg(test)]
+mod aoc4 {
+    use super::*;
+
+    #[test]
+    fn properties() {
+        prop::check(50, generate, |inp| {
+            let mirrored: Vec<String> = inp.iter().map(|l| l.chars().rev().collect()).collect();
+            let cols = inp[0].len();
+            let transposed: Vec<String> = (0..cols).map(|c| inp.iter().map(|l| l.as_bytes()[c] as char).collect()).collect();
+            let context = Context::new(inp).unwrap();
+            // Words are read in every direction, so mirroring or transposing the grid changes nothing
+            for other in [mirrored, transposed] {
+                let other = Context::new(other).unwrap();
+                assert_eq!((other.part1(), other.part2()), (context.part1(), context.part2()));
+            }
+        });
+    }
+
+    #[test]
+    fn example1() {
+        let text: Vec<String> = read_day(4, "example").expect("couldn't read input - aoc4");
+        let context = Context::new(text).unwrap();
+        println!("Part1: {:?}", context.part1());
+        println!("Part2: {:?}", context.part2());
+    }
+
+    #[test]
+    fn actual() {
+        let text: Vec<String> = read_day(4, "input").expect("couldn't read input - aoc4");
+        let context = Context::new(text).unwrap();
+        println!("Part1: {:?}", bench(|| context.part1(), Some("part1")));
+        println!("Part2: {:?}", bench(|| context.part2(), Some("part2")));
+    }
+}
\ No newline at end of file
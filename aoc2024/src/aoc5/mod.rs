@@ -0,0 +1,242 @@
+use crate::read_day;
+use aoc::utils::bench;
+use aoc::utils::prop;
+use aoc::utils::rng::Rng;
+use aoc::{Answer, Implementation};
+use aoc::error::AocError;
+use aoc::utils::parse::{list, split_blank};
+use std::{cmp::Ordering, collections::{HashMap, HashSet}, ops::Index};
+
+#[derive(Debug)]
+pub struct Context {
+    vertices: HashSet<u32>,
+    edges: HashMap<u32, Vec<u32>>,
+    reverse_edges: HashMap<u32, Vec<u32>>,
+    page_orderings: Vec<Vec<u32>>,
+}
+
+impl Context {
+    pub fn new(inp: Vec<String>) -> Result<Context, AocError> {
+        let mut vertices = HashSet::new();
+        let mut edges: HashMap<u32, Vec<u32>> = HashMap::new();
+        let mut reverse_edges: HashMap<u32, Vec<u32>> = HashMap::new();
+        let mut page_orderings = vec![];
+        let (rules, updates) = split_blank(&inp, "a blank line after the ordering rules")?;
+        for (i, line) in rules.enumerate() {
+            let edge: Vec<u32> = list(i, line, '|')?;
+            if edge.len() != 2 {
+                return Err(AocError::malformed(i, "expected a rule of the form X|Y"));
+            }
+            edges.entry(edge[0]).and_modify(|c: &mut Vec<u32>| (*c).push(edge[1])).or_insert(vec![edge[1]]);
+            reverse_edges.entry(edge[1]).and_modify(|c: &mut Vec<u32>| (*c).push(edge[0])).or_insert(vec![edge[0]]);
+        }
+        for (i, line) in updates.enumerate().filter(|(_, l)| !l.trim().is_empty()) {
+            let verts: Vec<u32> = list(i, line, ',')?;
+            vertices.extend(verts.iter());
+            page_orderings.push(verts);
+        }
+        Ok(Context {
+            vertices: vertices,
+            edges: edges,
+            reverse_edges: reverse_edges,
+            page_orderings: page_orderings
+        })
+    }
+
+    pub fn part1(&self) -> (u32, Vec<usize>) {
+        let mut sum = 0;
+        let mut correct = vec![];
+        for (k, ordering) in self.page_orderings.iter().enumerate() {
+            let mut flag = true;
+            for i in 0..ordering.len() {
+                for j in i + 1..ordering.len() {
+                    if self.edges.contains_key(&ordering[j]) && self.edges.get(&ordering[j]).unwrap().contains(&ordering[i]) {
+                        flag = false;
+                        break;
+                    }
+                }
+                if !flag {
+                    break;
+                }
+            }
+            if flag {
+                correct.push(k);
+                sum += ordering[ordering.len() / 2];
+            }
+        }
+        (sum, correct)
+    }
+
+    // Overall graph is not a DAG - has cycles. The rules between the pages of a single update
+    // usually don't, so only edges within `pages` are followed. `None` if they do.
+    fn topo_sort(&self, node: &u32, pages: &HashSet<u32>, permanent: &mut HashSet<u32>, temporary: &mut HashSet<u32>, sorted_list: &mut Vec<u32>) -> Option<()> {
+        if permanent.contains(node) {
+            return Some(());
+        }
+        if temporary.contains(node) {
+            return None;
+        }
+        temporary.insert(*node);
+        let egdes = self.reverse_edges.get(node);
+        match egdes {
+            Some(edge) => {
+                for e in edge.iter().filter(|e| pages.contains(e)) {
+                    self.topo_sort(e, pages, permanent, temporary, sorted_list)?;
+                }
+            },
+            None => (),
+        }
+        temporary.remove(node);
+        permanent.insert(*node);
+        sorted_list.push(*node);
+        Some(())
+    }
+
+    // The update in an order that follows its rules, `None` if they contradict each other
+    fn sorted(&self, ordering: &Vec<u32>) -> Option<Vec<u32>> {
+        let pages: HashSet<u32> = ordering.iter().copied().collect();
+        let (mut permanent, mut temporary, mut sorted_list) = (HashSet::new(), HashSet::new(), vec![]);
+        for page in ordering {
+            self.topo_sort(page, &pages, &mut permanent, &mut temporary, &mut sorted_list)?;
+        }
+        Some(sorted_list)
+    }
+
+    fn part2(&self, correct_list: &Vec<usize>) -> Option<u32> {
+        let mut sum = 0;
+        for (k, ordering) in self.page_orderings.iter().enumerate() {
+            if correct_list.contains(&k) {
+                continue;
+            }
+            // Swapping pages would go round the cycle forever
+            self.sorted(ordering)?;
+            let mut new_ordering = ordering.clone();
+            let mut i = 0;
+            while i < new_ordering.len() {
+                let mut num_swaps = 0;
+                for j in i+1..new_ordering.len() {
+                    if self.edges.contains_key(&new_ordering[j]) && self.edges.get(&new_ordering[j]).unwrap().contains(&new_ordering[i]) {
+                        let tmp = new_ordering[j];
+                        new_ordering[j] = new_ordering[i];
+                        new_ordering[i] = tmp;
+                        num_swaps += 1;
+                        break;
+                    }
+                }
+                if num_swaps == 0 {
+                    i += 1;
+                }
+            }
+            sum += new_ordering[new_ordering.len() / 2];
+        }
+        Some(sum)
+    }
+
+    // Part 2 by sorting each incorrect update topologically instead of swapping pages
+    fn part2_topo(&self, correct_list: &Vec<usize>) -> Option<u32> {
+        let mut sum = 0;
+        for (k, ordering) in self.page_orderings.iter().enumerate() {
+            if correct_list.contains(&k) {
+                continue;
+            }
+            let sorted_list = self.sorted(ordering)?;
+            sum += sorted_list[sorted_list.len() / 2];
+        }
+        Some(sum)
+    }
+}
+
+// Rules between every pair of a few pages, taken from one random order, and updates of an odd
+// number of those pages, some already in order
+pub fn generate(rng: &mut Rng) -> Vec<String> {
+    let mut pages: Vec<u32> = (10..100).collect();
+    rng.shuffle(&mut pages);
+    pages.truncate(rng.range(3, 12) as usize);
+    let mut lines = vec![];
+    for i in 0..pages.len() {
+        for j in i + 1..pages.len() {
+            lines.push(format!("{}|{}", pages[i], pages[j]));
+        }
+    }
+    rng.shuffle(&mut lines);
+    lines.push(String::new());
+    for _ in 0..rng.range(1, 10) {
+        let mut update = pages.clone();
+        rng.shuffle(&mut update);
+        update.truncate(rng.range(0, (pages.len() as i64 - 1) / 2) as usize * 2 + 1);
+        if rng.chance(0.4) {
+            update.sort_by_key(|p| pages.iter().position(|q| q == p));
+        }
+        lines.push(update.iter().map(|p| p.to_string()).collect::<Vec<String>>().join(","));
+    }
+    lines
+}
+
+impl aoc::Solution for Context {
+    fn parse(inp: Vec<String>) -> Result<Self, AocError> {
+        Context::new(inp)
+    }
+
+    fn part1(&self) -> Answer {
+        Context::part1(self).0.into()
+    }
+
+    fn part2(&self) -> Answer {
+        let (_, correct) = Context::part1(self);
+        Context::part2(self, &correct).into()
+    }
+
+    fn alternatives(&self) -> Vec<Implementation<'_>> {
+        vec![Implementation::new("topo-sort", 2, || {
+            let (_, correct) = Context::part1(self);
+            self.part2_topo(&correct).into()
+        })]
+    }
+}
+
+#[cfg(test)]
+mod aoc5 {
+    use super::*;
+
+    #[test]
+    fn properties() {
+        prop::check(50, generate, |inp| {
+            let context = Context::new(inp.clone()).unwrap();
+            let (part1, correct) = context.part1();
+            let part2 = context.part2(&correct).unwrap();
+            assert_eq!(context.part2_topo(&correct), Some(part2));
+            // Putting every update in order moves its middle page from part 2 to part 1
+            let rules = inp.iter().position(|l| l.is_empty()).unwrap();
+            let ordered: Vec<String> = context.page_orderings.iter()
+                .map(|update| {
+                    let mut sorted = update.clone();
+                    sorted.sort_by_key(|p| std::cmp::Reverse(update.iter().filter(|q| inp.contains(&format!("{}|{}", p, q))).count()));
+                    sorted.iter().map(|p| p.to_string()).collect::<Vec<String>>().join(",")
+                })
+                .collect();
+            let ordered = Context::new(inp[..=rules].iter().cloned().chain(ordered).collect()).unwrap();
+            let (all, correct) = ordered.part1();
+            assert_eq!(all, part1 + part2);
+            assert_eq!((correct.len(), ordered.part2(&correct)), (ordered.page_orderings.len(), Some(0)));
+        });
+    }
+
+    #[test]
+    fn example1() {
+        let text: Vec<String> = read_day(5, "example").expect("couldn't read input - aoc5");
+        let context = Context::new(text).unwrap();
+        //println!("context: {:?}", context);
+        let (part1, correct) = context.part1();
+        println!("Part1: {:?}", part1);
+        println!("Part2: {:?}", context.part2(&correct));
+    }
+
+    #[test]
+    fn actual() {
+        let text: Vec<String> = read_day(5, "input").expect("couldn't read input - aoc5");
+        let context = Context::new(text).unwrap();
+        let (part1, correct) = bench(|| context.part1(), Some("part1"));
+        println!("Part1: {:?}", part1);
+        println!("Part2: {:?}", bench(|| context.part2(&correct), Some("part2")));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,316 @@
+use crate::read_day;
+use aoc::utils::bench;
+use aoc::utils::prop;
+use aoc::utils::rng::Rng;
+use aoc::Answer;
+use aoc::error::AocError;
+use aoc::utils::grid::Grid;
+use aoc::utils::geometry::{Dir, Point};
+use aoc::utils::render::{Canvas, Palette, GREY, ORANGE, RED, WHITE};
+use aoc::utils::frames::Recorder;
+use aoc::debugger::{Event, Simulation};
+use std::collections::HashSet;
+use std::fmt::{self, Debug, Formatter};
+use std::cmp::PartialEq;
+use rayon::prelude::*;
+
+#[derive(PartialEq, Clone)]
+enum Tile {
+    Empty,
+    Brick
+}
+
+impl Debug for Tile {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Tile::Empty => write!(f, "."),
+            Tile::Brick => write!(f, "#"),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct Context {
+    matrix: Grid<Tile>,
+    init_pos: (usize, usize)
+}
+
+impl Debug for Context {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        // Print each row of the matrix on a new line
+        write!(f, "{:?}", self.matrix)?;
+
+        // Leave two blank lines
+        writeln!(f)?;
+        writeln!(f)?;
+
+        // Print the init_pos
+        write!(f, "init_pos: {:?}", self.init_pos)
+    }
+}
+
+impl Context {
+    pub fn new(inp: Vec<String>) -> Result<Context, AocError> {
+        let mut init_pos: (usize, usize) = (usize::MAX, usize::MAX);
+        let matrix = Grid::parse(&inp, |(i, j), c| match c {
+            '.' => Ok(Tile::Empty),
+            '#' => Ok(Tile::Brick),
+            '^' => {
+                init_pos = (i, j);
+                Ok(Tile::Empty)
+            },
+            _ => Err(AocError::unexpected_char(i, j, c)),
+        })?;
+        if init_pos == (usize::MAX, usize::MAX) {
+            return Err(AocError::missing("guard '^'"));
+        }
+        let context = Context {
+            matrix: matrix,
+            init_pos: init_pos
+        };
+        if !context.leaves() {
+            return Err(AocError::missing("way off the map for the guard"));
+        }
+        Ok(context)
+    }
+
+    // Whether the guard walks off the map, rather than in a loop or on the spot when walled in
+    fn leaves(&self) -> bool {
+        let mut seen = HashSet::new();
+        let (mut pos, mut dir) = (Point::from_index(self.init_pos), Dir::N);
+        loop {
+            if !seen.insert((pos, dir)) {
+                return false;
+            }
+            let next = pos + dir;
+            match self.matrix.index_of(next) {
+                None => return true,
+                Some(_) if self.matrix[next] == Tile::Brick => dir = dir.turn_right(),
+                Some(_) => pos = next,
+            }
+        }
+    }
+
+    // The guard's next position and direction, `None` once it leaves the map or if it is walled in
+    fn step(&self, pos: Point, mut dir: Dir) -> Option<(Point, Dir)> {
+        for _ in 0..4 {
+            let next = pos + dir;
+            match self.matrix.index_of(next) {
+                None => return None,
+                Some(_) if self.matrix[next] == Tile::Brick => dir = dir.turn_right(),
+                Some(_) => return Some((next, dir)),
+            }
+        }
+        None
+    }
+
+    pub fn part1(&self) -> (usize, Vec<(usize, usize)>) {
+        let mut visited: Grid<bool> = Grid::new(self.matrix.rows(), self.matrix.cols(), false);
+        visited[self.init_pos] = true;
+        let (mut pos, mut dir) = (Point::from_index(self.init_pos), Dir::N);
+        while let Some(next) = self.step(pos, dir) {
+            (pos, dir) = next;
+            visited[pos] = true;
+        }
+        let guard_path: Vec<(usize, usize)> = visited.find_all(&true).filter(|&p| p != self.init_pos).collect();
+        (guard_path.len() + 1, guard_path)
+    }
+
+    fn helper(&self, obstruction: Point) -> bool {
+        // Directions the guard walked each cell in, one bit per direction
+        let bit = |dir: Dir| 1u8 << Dir::ORTHOGONAL.iter().position(|&d| d == dir).unwrap();
+        let mut visited: Grid<u8> = Grid::new(self.matrix.rows(), self.matrix.cols(), 0);
+        let mut pos = Point::from_index(self.init_pos);
+        let mut dir = Dir::N;
+        visited[pos] = bit(dir);
+        loop {
+            let mut next = pos + dir;
+            let mut turns = 0;
+            while self.matrix.index_of(next).is_some() && (self.matrix[next] == Tile::Brick || obstruction == next) {
+                // Walled in by the obstruction, the guard stays put forever
+                if turns == 4 {
+                    return true;
+                }
+                dir = dir.turn_right();
+                next = pos + dir;
+                turns += 1;
+            }
+            if self.matrix.index_of(next).is_none() {
+                return false;
+            }
+            if visited[next] & bit(dir) != 0 {
+                return true;
+            }
+            pos = next;
+            visited[pos] |= bit(dir);
+        }
+    }
+
+    pub fn part2(&self, guard_path: &Vec<(usize, usize)>) -> usize {
+        guard_path.par_iter()
+        .map(|&p| if self.helper(Point::from_index(p)) { 1 } else { 0 })
+        .sum()
+    }
+}
+
+// The guard's walk one cell at a time, tagging turns and cells walked through before
+struct Patrol {
+    context: Context,
+    pos: Point,
+    dir: Dir,
+    visited: Grid<bool>,
+}
+
+impl Patrol {
+    fn new(context: Context) -> Patrol {
+        let mut visited = Grid::new(context.matrix.rows(), context.matrix.cols(), false);
+        visited[context.init_pos] = true;
+        Patrol { pos: Point::from_index(context.init_pos), dir: Dir::N, visited, context }
+    }
+}
+
+impl Simulation for Patrol {
+    fn reset(&mut self) {
+        *self = Patrol::new(self.context.clone());
+    }
+
+    fn step(&mut self) -> Option<Event> {
+        let (next, dir) = self.context.step(self.pos, self.dir)?;
+        let event = Event::new(format!("{} to {}", dir.to_arrow().unwrap(), next))
+            .tag("turn", dir != self.dir)
+            .tag("revisit", self.visited[next]);
+        (self.pos, self.dir) = (next, dir);
+        self.visited[next] = true;
+        Some(event)
+    }
+
+    fn view(&self) -> Grid<char> {
+        let mut view = self.context.matrix.map(|t| if *t == Tile::Brick { '#' } else { '.' });
+        for pos in self.visited.find_all(&true) {
+            view[pos] = 'X';
+        }
+        view[self.pos] = self.dir.to_arrow().unwrap();
+        view
+    }
+
+    fn palette(&self) -> Palette<char> {
+        Palette::new(RED).with('.', WHITE).with('#', GREY).with('X', ORANGE)
+    }
+}
+
+// Lab with scattered obstructions and a guard who eventually walks off the map
+pub fn generate(rng: &mut Rng) -> Vec<String> {
+    loop {
+        let (rows, cols) = (rng.range(3, 14) as usize, rng.range(3, 14) as usize);
+        let density = rng.range(5, 25) as f64 / 100.0;
+        let mut grid: Vec<Vec<char>> = (0..rows).map(|_| (0..cols).map(|_| if rng.chance(density) { '#' } else { '.' }).collect()).collect();
+        let guard = (rng.below(rows), rng.below(cols));
+        grid[guard.0][guard.1] = '^';
+        // Retry when the guard's own walk is a loop
+        let mut pos = Point::from_index(guard);
+        let mut dir = Dir::N;
+        let mut seen = HashSet::new();
+        let leaves = loop {
+            if !seen.insert((pos, dir)) {
+                break false;
+            }
+            let next = pos + dir;
+            match next.to_index().and_then(|(r, c)| grid.get(r).and_then(|row| row.get(c))) {
+                None => break true,
+                Some('#') => dir = dir.turn_right(),
+                _ => pos = next,
+            }
+        };
+        if leaves {
+            return grid.iter().map(|row| row.iter().collect()).collect();
+        }
+    }
+}
+
+impl aoc::Solution for Context {
+    fn parse(inp: Vec<String>) -> Result<Self, AocError> {
+        Context::new(inp)
+    }
+
+    fn part1(&self) -> Answer {
+        Context::part1(self).0.into()
+    }
+
+    fn part2(&self) -> Answer {
+        let (_, path) = Context::part1(self);
+        Context::part2(self, &path).into()
+    }
+
+    // The guard's route from the start in red
+    fn pictures(&self) -> Vec<(String, Canvas)> {
+        let (_, path) = Context::part1(self);
+        let mut canvas = Canvas::from_grid(&self.matrix, &Palette::new(WHITE).with(Tile::Brick, GREY));
+        canvas.paint(path, ORANGE);
+        canvas.paint([self.init_pos], RED);
+        vec![("path".to_owned(), canvas)]
+    }
+
+    // The guard walking off the map, with the route so far in orange
+    fn animations(&self, every: usize) -> Vec<(String, Vec<Canvas>)> {
+        let lab = Canvas::from_grid(&self.matrix, &Palette::new(WHITE).with(Tile::Brick, GREY));
+        let draw = |route: &Vec<(usize, usize)>, guard: Point| {
+            let mut canvas = lab.clone();
+            canvas.paint(route.iter().copied(), ORANGE);
+            canvas.paint(self.matrix.index_of(guard), RED);
+            canvas
+        };
+        let (mut pos, mut dir) = (Point::from_index(self.init_pos), Dir::N);
+        let mut route = vec![self.init_pos];
+        let mut recorder = Recorder::new(every);
+        recorder.frame(draw(&route, pos));
+        while let Some(next) = self.step(pos, dir) {
+            (pos, dir) = next;
+            route.push(self.matrix.index_of(pos).unwrap());
+            recorder.step(|| draw(&route, pos));
+        }
+        vec![("walk".to_owned(), recorder.into_frames())]
+    }
+
+    fn simulations(&self) -> Vec<(String, Box<dyn Simulation>)> {
+        vec![("patrol".to_owned(), Box::new(Patrol::new(self.clone())))]
+    }
+}
+
+#[allow(unused_imports)]
+#[cfg(test)]
+mod aoc6 {
+    use super::*;
+
+    #[test]
+    fn properties() {
+        prop::check(50, generate, |inp| {
+            let context = Context::new(inp).unwrap();
+            let (visited, path) = context.part1();
+            let empty = context.matrix.find_all(&Tile::Empty).count();
+            // Only cells the guard walks through are worth obstructing
+            assert!(visited >= 1 && visited <= empty);
+            assert_eq!(path.len(), visited - 1);
+            assert!(context.part2(&path) <= path.len());
+        });
+    }
+
+    #[test]
+    fn example1() {
+        let text: Vec<String> = read_day(6, "example").expect("couldn't read input - aoc6");
+        let context = Context::new(text).unwrap();
+        let (part1, path) = context.part1();
+        println!("Guard path length: {}", path.len());
+        println!("Part1: {:?}", part1);
+        println!("Part2: {:?}", context.part2(&path));
+    }
+
+    #[test]
+    fn actual() {
+        let text: Vec<String> = read_day(6, "input").expect("couldn't read input - aoc6");
+        let context = Context::new(text).unwrap();
+        let (part1, path) = bench(|| context.part1(), Some("part1"));
+        println!("Guard path length: {}", path.len());
+        println!("Part1: {:?}", part1);
+        println!("Part2: {:?}", bench(|| context.part2(&path), Some("part2")));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,234 @@
+use crate::read_day;
+use aoc::utils::bench;
+use aoc::utils::prop;
+use aoc::utils::rng::Rng;
+use aoc::Answer;
+use aoc::error::{AocError, parse_token};
+use std::fmt::{self, write, Debug, Formatter};
+use std::cmp::PartialEq;
+use std::ptr::eq;
+use rayon::prelude::*;
+use itertools::Itertools;
+
+type NumType = u64;
+
+#[derive(Debug)]
+struct Equation {
+    pub result: NumType,
+    pub nums: Vec<NumType>
+}
+#[derive(Copy, Clone, PartialEq)]
+enum Operator {
+    Add,
+    Mul,
+    Concat,
+    None
+}
+
+impl Debug for Operator {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Operator::Add => write!(f, "+"),
+            Operator::Mul => write!(f, "*"),
+            Operator::None => write!(f, " "),
+            Operator::Concat => write!(f, "||"),
+        }
+    }
+}
+
+impl Operator {
+    // The value `x` for which `x op y` is `z`, if there is one. Multiplying by 0 is left to
+    // the caller, since any `x` gives 0.
+    fn undo(self, z: NumType, y: NumType) -> Option<NumType> {
+        match self {
+            Operator::Add => z.checked_sub(y),
+            Operator::Mul => (y != 0 && z % y == 0).then(|| z / y),
+            Operator::Concat => {
+                let shift = 10_u64.checked_pow(y.to_string().len() as u32)?;
+                (z % shift == y).then(|| z / shift)
+            },
+            Operator::None => panic!("Unknown Operator"),
+        }
+    }
+}
+
+const OPERATORS_1: [Operator; 2] = [Operator::Add, Operator::Mul];
+const OPERATORS_2: [Operator; 3] = [Operator::Add, Operator::Mul, Operator::Concat];
+
+#[derive(Debug)]
+pub struct Context {
+    equations: Vec<Equation>,
+}
+
+impl Context {
+    pub fn new(inp: Vec<String>) -> Result<Context, AocError> {
+        let equations: Vec<Equation> = inp.iter().enumerate().map(|(i, s)| {
+            let t1: Vec<&str> = s.split(':').collect();
+            if t1.len() != 2 {
+                return Err(AocError::malformed(i, "expected `result: numbers`"));
+            }
+            let result = parse_token::<NumType>(i, s, t1[0])?;
+            let nums: Vec<NumType> = t1[1]
+                .trim()
+                .split_whitespace()
+                .into_iter()
+                .map(|f| parse_token::<NumType>(i, s, f))
+                .collect::<Result<_, AocError>>()?;
+            if nums.is_empty() {
+                return Err(AocError::malformed(i, "equation has no numbers"));
+            }
+            Ok(Equation {
+                result: result,
+                nums: nums
+            })
+        }).collect::<Result<_, AocError>>()?;
+        if equations.is_empty() {
+            return Err(AocError::EmptyInput);
+        }
+        Ok(Context {
+            equations: equations
+        })
+    }
+
+    // `None` if the result doesn't fit in NumType
+    fn concatenate(x: NumType, y: NumType) -> Option<NumType> {
+        // Calculate the number of digits in y
+        let y_digits = y.to_string().len() as u32;
+    
+        // Shift x to the left by the number of digits in y
+        let shifted_x = x.checked_mul(10_u64.checked_pow(y_digits)?)?;
+    
+        // Add y to the shifted x
+        shifted_x.checked_add(y)
+    }
+
+    // The result if some choice of `operators` between the numbers gives it, else 0. Works
+    // back from the result, undoing the last number each time and keeping the distinct values
+    // that are left: trying every combination of operators takes forever for long equations,
+    // and few values can be undone.
+    fn helper(equation: &Equation, operators: &[Operator]) -> NumType {
+        let mut values = vec![equation.result];
+        for &n in equation.nums[1..].iter().rev() {
+            // Whatever comes before, multiplying it by 0 gives 0
+            if n == 0 && values.contains(&0) && operators.contains(&Operator::Mul) {
+                return equation.result;
+            }
+            values = values.iter().flat_map(|&v| operators.iter().filter_map(move |op| op.undo(v, n))).collect();
+            values.sort_unstable();
+            values.dedup();
+        }
+        if values.contains(&equation.nums[0]) { equation.result } else { 0 }
+    }
+
+    // `None` if the total overflows
+    pub fn part1(&self) -> Option<NumType> {
+        self.equations.par_iter()
+        .map(|eq| Some(Context::helper(eq, &OPERATORS_1)))
+        .try_reduce(|| 0, |a, b| a.checked_add(b))
+    }
+
+    pub fn part2(&self) -> Option<NumType> {
+        self.equations.par_iter()
+        .map(|eq| Some(Context::helper(eq, &OPERATORS_2)))
+        .try_reduce(|| 0, |a, b| a.checked_add(b))
+    }
+}
+
+// Equations of two to six numbers, most of them made true with random operators
+pub fn generate(rng: &mut Rng) -> Vec<String> {
+    (0..rng.range(1, 20))
+        .map(|_| {
+            let nums: Vec<NumType> = (0..rng.range(2, 6)).map(|_| rng.range(1, 20) as NumType).collect();
+            let mut result = nums[0];
+            for &n in &nums[1..] {
+                result = match rng.below(3) {
+                    0 => result + n,
+                    1 => result * n,
+                    // At most six numbers below 20, far from overflowing
+                    _ => Context::concatenate(result, n).unwrap(),
+                };
+            }
+            if rng.chance(0.3) {
+                result += rng.range(1, 5) as NumType;
+            }
+            let nums: Vec<String> = nums.iter().map(|n| n.to_string()).collect();
+            format!("{}: {}", result, nums.join(" "))
+        })
+        .collect()
+}
+
+impl aoc::Solution for Context {
+    fn parse(inp: Vec<String>) -> Result<Self, AocError> {
+        Context::new(inp)
+    }
+
+    fn part1(&self) -> Answer {
+        Context::part1(self).into()
+    }
+
+    fn part2(&self) -> Answer {
+        Context::part2(self).into()
+    }
+}
+
+#[allow(unused_imports)]
+#[cfg(test)]
+mod aoc7 {
+    use super::*;
+
+    #[test]
+    fn properties() {
+        prop::check(50, generate, |inp| {
+            // A sum of the numbers is always reachable with additions alone
+            let sums: Vec<String> = inp.iter()
+                .map(|l| {
+                    let nums = l.split_once(':').unwrap().1;
+                    format!("{}:{}", nums.split_whitespace().map(|n| n.parse::<NumType>().unwrap()).sum::<NumType>(), nums)
+                })
+                .collect();
+            let context = Context::new(inp).unwrap();
+            assert!(context.part1() <= context.part2());
+            let sums = Context::new(sums).unwrap();
+            let total: NumType = sums.equations.iter().map(|eq| eq.result).sum();
+            assert_eq!((sums.part1(), sums.part2()), (Some(total), Some(total)));
+        });
+    }
+
+    #[test]
+    fn overflow() {
+        let context = Context::new(vec!["10: 99999999999 99999999999 99999999999".to_owned()]).unwrap();
+        assert_eq!((context.part1(), context.part2()), (Some(0), Some(0)));
+        let context = Context::new(vec![format!("{0}: {0}", NumType::MAX), "1: 1".to_owned()]).unwrap();
+        assert_eq!(context.part1(), None);
+        // A value that overflowed still multiplies to 0
+        let context = Context::new(vec!["0: 99999999999 99999999999 99999999999 0".to_owned()]).unwrap();
+        assert_eq!(context.part1(), Some(0));
+        let context = Context::new(vec!["5: 99999999999 99999999999 99999999999 0 5".to_owned()]).unwrap();
+        assert_eq!(context.part1(), Some(5));
+    }
+
+    #[test]
+    fn long_equations() {
+        let ones = vec!["1"; 30].join(" ");
+        let context = Context::new(vec![format!("1000: {}", ones), format!("30: {}", ones), format!("1111: {}", ones)]).unwrap();
+        assert_eq!((context.part1(), context.part2()), (Some(30), Some(30 + 1000 + 1111)));
+    }
+
+    #[test]
+    fn example1() {
+        let text: Vec<String> = read_day(7, "example").expect("couldn't read input - aoc7");
+        let context = Context::new(text).unwrap();
+        let part1 = context.part1();
+        println!("Part1: {:?}", part1);
+        println!("Part2: {:?}", context.part2());
+    }
+
+    #[test]
+    fn actual() {
+        let text: Vec<String> = read_day(7, "input").expect("couldn't read input - aoc7");
+        let context = Context::new(text).unwrap();
+        let part1 = bench(|| context.part1(), Some("part2"));
+        println!("Part1: {:?}", part1);
+        println!("Part2: {:?}", bench(|| context.part2(), Some("part2")));
+    }
+}
\ No newline at end of file
@@ -1,40 +1,43 @@
-use crate::utils::{read_input, bench};
-use std::fmt::{write, Debug, Formatter, Result};
+use crate::read_day;
+use aoc::utils::bench;
+use aoc::utils::prop;
+use aoc::utils::rng::Rng;
+use aoc::Answer;
+use aoc::error::AocError;
+use aoc::utils::grid::Grid;
+use std::fmt::{write, Debug, Formatter};
 use std::collections::{HashMap, HashSet};
 use rayon::prelude::*;
 
 type Location = (isize, isize);
 
 #[derive(Debug)]
-struct Context {
+pub struct Context {
     antennas: HashMap<char, Vec<Location>>,
-    rowsize: usize,
-    colsize: usize,
+    map: Grid<char>,
 }
 
 impl Context {
-    pub fn new(inp: Vec<String>) -> Context {
+    pub fn new(inp: Vec<String>) -> Result<Context, AocError> {
         let mut map: HashMap<char, Vec<(isize, isize)>> = HashMap::new();
-        let rowsize = inp.len();
-        let colsize = inp[0].len();
-        for i in 0..inp.len() {
-            for (j, c) in inp[i].chars().enumerate() {
-                if c.is_ascii_uppercase() || c.is_ascii_lowercase() || c.is_numeric() {
-                    map.entry(c)
-                        .and_modify(|v| v.push((i as isize, j as isize)))
-                        .or_insert(vec![(i as isize, j as isize)]);
-                }
+        let grid = Grid::parse(&inp, |(i, j), c| {
+            if c.is_ascii_uppercase() || c.is_ascii_lowercase() || c.is_numeric() {
+                map.entry(c)
+                    .and_modify(|v| v.push((i as isize, j as isize)))
+                    .or_insert(vec![(i as isize, j as isize)]);
+            } else if c != '.' && c != '#' {
+                return Err(AocError::unexpected_char(i, j, c));
             }
-        }
-        Context {
+            Ok(c)
+        })?;
+        Ok(Context {
             antennas: map,
-            rowsize: rowsize,
-            colsize: colsize
-        }
+            map: grid,
+        })
     }
 
     fn within_grid(&self, l: Location) -> bool {
-        l.0 >= 0 && l.0 < self.rowsize as isize && l.1 >= 0 && l.1 < self.colsize as isize
+        self.map.contains(l)
     }
 
     fn compute_antinodes(&self, loc1: &Location, loc2: &Location) -> Vec<Location> {
@@ -134,15 +137,56 @@ impl Context {
     }
 }
 
+// Map with a few antennas of a handful of frequencies
+pub fn generate(rng: &mut Rng) -> Vec<String> {
+    let (rows, cols) = (rng.range(1, 15) as usize, rng.range(1, 15) as usize);
+    let frequencies: Vec<char> = (0..rng.range(1, 4)).map(|_| *rng.pick(&['a', 'A', 'z', '0', '7', 'Q'])).collect();
+    let density = rng.range(2, 12) as f64 / 100.0;
+    (0..rows)
+        .map(|_| (0..cols).map(|_| if rng.chance(density) { *rng.pick(&frequencies) } else { '.' }).collect())
+        .collect()
+}
+
+impl aoc::Solution for Context {
+    fn parse(inp: Vec<String>) -> Result<Self, AocError> {
+        Context::new(inp)
+    }
+
+    fn part1(&self) -> Answer {
+        Context::part1(self).into()
+    }
+
+    fn part2(&self) -> Answer {
+        Context::part2(self).into()
+    }
+}
+
 #[allow(unused_imports)]
 #[cfg(test)]
 mod aoc8 {
     use super::*;
 
+    #[test]
+    fn properties() {
+        prop::check(50, generate, |inp| {
+            let mirrored: Vec<String> = inp.iter().map(|l| l.chars().rev().collect()).collect();
+            let flipped: Vec<String> = inp.iter().rev().cloned().collect();
+            let context = Context::new(inp).unwrap();
+            // Resonant antinodes include the plain ones and every antenna
+            let antennas: usize = context.antennas.values().map(|v| v.len()).sum();
+            assert!(context.part1() <= context.part2());
+            assert!(antennas <= context.part2());
+            for other in [mirrored, flipped] {
+                let other = Context::new(other).unwrap();
+                assert_eq!((other.part1(), other.part2()), (context.part1(), context.part2()));
+            }
+        });
+    }
+
     #[test]
     fn example1() {
-        let text: Vec<String> = read_input("src/aoc8/example").expect("couldn't read input - aoc8");
-        let context = Context::new(text);
+        let text: Vec<String> = read_day(8, "example").expect("couldn't read input - aoc8");
+        let context = Context::new(text).unwrap();
         //println!("context: {:?}", context);
         let part1 = context.part1();
         println!("Part1: {:?}", part1);
@@ -151,8 +195,8 @@ mod aoc8 {
 
     #[test]
     fn actual() {
-        let text: Vec<String> = read_input("src/aoc8/input").expect("couldn't read input - aoc8");
-        let context = Context::new(text);
+        let text: Vec<String> = read_day(8, "input").expect("couldn't read input - aoc8");
+        let context = Context::new(text).unwrap();
         let part1 = bench(|| context.part1(), Some("part1"));
         println!("Part1: {:?}", part1);
         println!("Part2: {:?}", bench(|| context.part2(), Some("part2")));
@@ -1,5 +1,10 @@
-use crate::utils::{read_input, bench};
-use std::fmt::{write, Debug, Formatter, Result};
+use crate::read_day;
+use aoc::utils::bench;
+use aoc::utils::prop;
+use aoc::utils::rng::Rng;
+use aoc::Answer;
+use aoc::error::{AocError, get_line};
+use std::fmt::{write, Debug, Formatter};
 use rayon::prelude::*;
 
 type Num = usize;
@@ -13,22 +18,22 @@ struct Block {
 
 
 #[derive(Debug)]
-struct Context {
+pub struct Context {
     files: Vec<Block>,
     free: Vec<Block>,
 }
 
 impl Context {
-    pub fn new(inp: Vec<String>) -> Context {
+    pub fn new(inp: Vec<String>) -> Result<Context, AocError> {
         let mut files = vec![];
         let mut free = vec![];
         let mut tot_length = 0;
-        for (i, c) in inp[0].chars().enumerate() {
-            let l = c.to_digit(10).unwrap() as Num;
+        for (i, c) in get_line(&inp, 0, "the disk map")?.trim_end().chars().enumerate() {
+            let l = c.to_digit(10).ok_or(AocError::unexpected_char(0, i, c))? as Num;
             match i % 2 == 0 {
                 true => {
                     if l == 0 {
-                        panic!("zero sized file not supported");
+                        return Err(AocError::malformed(0, &format!("zero sized file at column {} not supported", i + 1)));
                     }
                     let f = Block {
                         start_index: tot_length,
@@ -51,10 +56,10 @@ impl Context {
             };
             tot_length += l;
         }
-        Context {
+        Ok(Context {
             files: files,
             free: free
-        }
+        })
     }
 
     fn compute_checksum(files: &Vec<Block>) -> Num {
@@ -68,6 +73,11 @@ impl Context {
     }
 
     pub fn part1(&self) -> Num {
+        Context::compute_checksum(&self.compact())
+    }
+
+    // Files after moving blocks one at a time from the end into the leftmost free space
+    fn compact(&self) -> Vec<Block> {
         let mut new_files: Vec<Block> = vec![];
 
         let mut files = self.files.clone();
@@ -129,10 +139,15 @@ impl Context {
             }
         }
         files.extend(new_files);
-        Context::compute_checksum(&files)
+        files
     }
 
     pub fn part2(&self) -> Num {
+        Context::compute_checksum(&self.defragment())
+    }
+
+    // Files after moving each whole file, last first, into the leftmost free span that fits it
+    fn defragment(&self) -> Vec<Block> {
         let mut files = self.files.clone();
         let mut free = self.free.clone();
 
@@ -159,7 +174,31 @@ impl Context {
                 }
             }
         }
-        Context::compute_checksum(&files)
+        files
+    }
+}
+
+// Disk map of files of 1 to 9 blocks separated by free spans of 0 to 9 blocks
+pub fn generate(rng: &mut Rng) -> Vec<String> {
+    let files = rng.range(1, 30);
+    let map: String = (0..files * 2 - 1)
+        .map(|k| if k % 2 == 0 { rng.range(1, 9) } else { rng.range(0, 9) })
+        .map(|l| char::from_digit(l as u32, 10).unwrap())
+        .collect();
+    vec![map]
+}
+
+impl aoc::Solution for Context {
+    fn parse(inp: Vec<String>) -> Result<Self, AocError> {
+        Context::new(inp)
+    }
+
+    fn part1(&self) -> Answer {
+        Context::part1(self).into()
+    }
+
+    fn part2(&self) -> Answer {
+        Context::part2(self).into()
     }
 }
 
@@ -168,10 +207,43 @@ impl Context {
 mod aoc9 {
     use super::*;
 
+    // File index of every block, `None` for free ones
+    fn render(files: &Vec<Block>) -> Vec<Option<Num>> {
+        let mut disk = vec![None; files.iter().map(|f| f.start_index + f.length).max().unwrap_or(0)];
+        for f in files {
+            for i in f.start_index..f.start_index + f.length {
+                assert_eq!(disk[i], None, "blocks overlap at {}", i);
+                disk[i] = Some(f.file_index);
+            }
+        }
+        disk
+    }
+
+    #[test]
+    fn properties() {
+        prop::check(50, generate, |inp| {
+            let context = Context::new(inp).unwrap();
+            let used: Num = context.files.iter().map(|f| f.length).sum();
+            for (files, checksum) in [(context.compact(), context.part1()), (context.defragment(), context.part2())] {
+                // Moving blocks around neither loses nor duplicates any, and the checksum is
+                // that of the rendered disk
+                let disk = render(&files);
+                assert_eq!(disk.iter().flatten().count(), used);
+                assert_eq!(checksum, disk.iter().enumerate().map(|(i, f)| i * f.unwrap_or(0)).sum());
+            }
+            // Compacting leaves no gaps
+            assert_eq!(render(&context.compact()).len(), used);
+            // Whole files keep their length
+            let mut moved = context.defragment();
+            moved.sort_by_key(|f| f.file_index);
+            assert!(moved.iter().zip(&context.files).all(|(m, f)| m.length == f.length && m.start_index <= f.start_index));
+        });
+    }
+
     #[test]
     fn example1() {
-        let text: Vec<String> = read_input("src/aoc9/example").expect("couldn't read input - aoc9");
-        let context = Context::new(text);
+        let text: Vec<String> = read_day(9, "example").expect("couldn't read input - aoc9");
+        let context = Context::new(text).unwrap();
         //println!("context: {:?}", context);
         let part1 = context.part1();
         println!("Part1: {:?}", part1);
@@ -180,8 +252,8 @@ mod aoc9 {
 
     #[test]
     fn actual() {
-        let text: Vec<String> = read_input("src/aoc9/input").expect("couldn't read input - aoc9");
-        let context = Context::new(text);
+        let text: Vec<String> = read_day(9, "input").expect("couldn't read input - aoc9");
+        let context = Context::new(text).unwrap();
         let part1 = bench(|| context.part1(), Some("part1"));
         println!("Part1: {:?}", part1);
         println!("Part2: {:?}", bench(|| context.part2(), Some("part2")));
@@ -0,0 +1,118 @@
+#![allow(warnings)]
+pub mod reference;
+mod aoc1;
+mod aoc2;
+mod aoc3;
+mod aoc4;
+mod aoc5;
+mod aoc6;
+mod aoc7;
+mod aoc8;
+mod aoc9;
+mod aoc10;
+mod aoc11;
+mod aoc12;
+mod aoc13;
+mod aoc14;
+mod aoc15;
+mod aoc16;
+
+mod aoc17;
+
+mod aoc18;
+
+use aoc::utils;
+use aoc::{boxed, Day, Year};
+use std::io::Error;
+
+/// All implemented days, in order.
+pub const DAYS: &[Day] = &[
+    Day { day: 1, parse: boxed::<aoc1::Context>, generate: aoc1::generate },
+    Day { day: 2, parse: boxed::<aoc2::Context>, generate: aoc2::generate },
+    Day { day: 3, parse: boxed::<aoc3::Context>, generate: aoc3::generate },
+    Day { day: 4, parse: boxed::<aoc4::Context>, generate: aoc4::generate },
+    Day { day: 5, parse: boxed::<aoc5::Context>, generate: aoc5::generate },
+    Day { day: 6, parse: boxed::<aoc6::Context>, generate: aoc6::generate },
+    Day { day: 7, parse: boxed::<aoc7::Context>, generate: aoc7::generate },
+    Day { day: 8, parse: boxed::<aoc8::Context>, generate: aoc8::generate },
+    Day { day: 9, parse: boxed::<aoc9::Context>, generate: aoc9::generate },
+    Day { day: 10, parse: boxed::<aoc10::Context>, generate: aoc10::generate },
+    Day { day: 11, parse: boxed::<aoc11::Context>, generate: aoc11::generate },
+    Day { day: 12, parse: boxed::<aoc12::Context>, generate: aoc12::generate },
+    Day { day: 13, parse: boxed::<aoc13::Context>, generate: aoc13::generate },
+    Day { day: 14, parse: boxed::<aoc14::Context>, generate: aoc14::generate },
+    Day { day: 15, parse: boxed::<aoc15::Context>, generate: aoc15::generate },
+    Day { day: 16, parse: boxed::<aoc16::Context>, generate: aoc16::generate },
+    Day { day: 17, parse: boxed::<aoc17::Context>, generate: aoc17::generate },
+    Day { day: 18, parse: boxed::<aoc18::Context>, generate: aoc18::generate },
+];
+
+/// Advent of Code 2024, with the days' files under this crate's `src`.
+pub const YEAR: Year = Year { year: 2024, dir: concat!(env!("CARGO_MANIFEST_DIR"), "/src"), days: DAYS };
+
+/// File `name` (e.g. "input", "example2") of a day of this year.
+pub fn read_day(day: u32, name: &str) -> Result<Vec<String>, Error> {
+    utils::read_day(&YEAR, day, name)
+}
+
+#[cfg(test)]
+mod aoc2024 {
+    use super::*;
+    use aoc::answers;
+    use aoc::robustness::{self, Failure};
+    use aoc::AocError;
+    use std::time::Duration;
+
+    #[test]
+    fn recorded() {
+        let mismatches = answers::mismatches(&YEAR);
+        assert!(mismatches.is_empty(), "{} mismatched answer(s):\n{}", mismatches.len(), mismatches.join("\n"));
+    }
+
+    fn parse_err(day: u32, text: &str) -> AocError {
+        let inp = text.lines().map(|l| l.to_owned()).collect();
+        match YEAR.get_day(day).unwrap().parse(inp) {
+            Ok(_) => panic!("aoc{} accepted {:?}", day, text),
+            Err(e) => e,
+        }
+    }
+
+    #[test]
+    fn diagnostics() {
+        assert_eq!(parse_err(1, "3   4\n4   x"), AocError::InvalidNumber { line: 2, column: 5, token: "x".to_owned() });
+        assert_eq!(parse_err(6, "..#.\n.^.?"), AocError::UnexpectedChar { line: 2, column: 4, found: '?' });
+        assert_eq!(parse_err(9, "12a4"), AocError::UnexpectedChar { line: 1, column: 3, found: 'a' });
+        assert_eq!(parse_err(13, "Button A: X+94, Y+34\nButton B: X+22, Y+67"), AocError::UnexpectedEnd { line: 3, expected: "prize".to_owned() });
+        assert_eq!(parse_err(15, "#@.#\n\n<>x"), AocError::UnexpectedChar { line: 3, column: 3, found: 'x' });
+        assert_eq!(parse_err(15, "@.\n..\n\n>>"), AocError::Malformed { line: 1, reason: "the warehouse map has to be surrounded by walls '#'".to_owned() });
+        assert_eq!(parse_err(16, ""), AocError::EmptyInput);
+        assert_eq!(parse_err(17, "Register A: 1\nRegister B: 0\nRegister C: 0\n\nProgram: 1,7,0,7"), AocError::Malformed { line: 5, reason: "instruction 1 has the reserved combo operand 7".to_owned() });
+        assert_eq!(parse_err(6, "..#.\n....").to_string(), "input has no guard '^'");
+        assert_eq!(parse_err(6, ".#.\n#^#\n.#.").to_string(), "input has no way off the map for the guard");
+        assert_eq!(parse_err(6, ".#...\n....#\n.....\n#^...\n...#.").to_string(), "input has no way off the map for the guard");
+    }
+
+    // Inputs that once made a day panic or hang
+    const CASES: &[(u32, &str)] = &[
+        // Products that overflow u64, and an equation too long to try every combination of operators
+        (7, "10: 99999999999 99999999999 99999999999\n1000: 3 5 7 11 13 17 19 23 29 31 37 41 43 47 53 59 61 67 71 73 79 83 89 97 2"),
+        // Velocities at the edge of i32
+        (14, "p=100,0 v=2147483647,0\np=0,102 v=-2147483648,-2147483648"),
+        // A guard walled in on all sides, and one whose own walk is a loop
+        (6, ".#.\n#^#\n.#."),
+        (6, ".#...\n....#\n.....\n#^...\n...#."),
+        // Jumps to the last index, leaving an opcode without operand
+        (17, "Register A: 1\nRegister B: 0\nRegister C: 0\n\nProgram: 3,1"),
+        // Shifts A by more than its 64 bits
+        (17, "Register A: 1\nRegister B: 0\nRegister C: 64\n\nProgram: 0,6,5,4"),
+    ];
+
+    #[test]
+    fn never_panics() {
+        let failures: Vec<Failure> = robustness::check_year(&YEAR, CASES, 5, Duration::from_secs(10));
+        if let Some(first) = failures.first() {
+            let list: Vec<String> = failures.iter().map(|f| f.to_string()).collect();
+            panic!("{} inputs broke a day:\n{}\nfirst input:\n{}", failures.len(), list.join("\n"), first.text);
+        }
+    }
+}
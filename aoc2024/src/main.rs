@@ -0,0 +1,489 @@
+use std::env;
+use std::io::{self, IsTerminal};
+use std::path::{Path, PathBuf};
+use std::panic;
+use std::process;
+use std::time::Duration;
+use aoc::{AocError, Day, Year};
+use aoc::utils::{day_file, InputSource};
+use aoc::utils::bench::{self, bench_day, BenchConfig};
+use aoc::utils::history;
+use aoc::utils::alloc::CountingAlloc;
+use aoc::utils::render::Export;
+use aoc::utils::frames::FrameFormat;
+use aoc::scaffold;
+use aoc::client::Client;
+use aoc::client::submit::Verdict;
+use aoc::runner;
+use aoc::compare;
+use aoc::debugger::{self, Debugger};
+
+// Only counts while `bench --alloc` measures a phase
+#[global_allocator]
+static ALLOC: CountingAlloc = CountingAlloc;
+
+// Every year with solutions, oldest first
+const YEARS: &[&Year] = &[&aoc2024::YEAR];
+
+const USAGE: &str = "usage: aoc2024 <command> [--year YEAR]
+
+commands:
+    run <day> [--part N] [--input PATH]   print the answers for a day
+    all                                   run every day in parallel and check the answers
+    list                                  print all registered days of the year
+    picture <day> [--input PATH] [--scale N] [--dir PATH]
+                                          save pictures of a day's puzzle as PNG
+    animate <day> [--input PATH] [--every N] [--delay MS] [--png] [--scale N] [--dir PATH]
+                                          save a day's simulation as an animated GIF
+    debug <day> [--input PATH] [--sim NAME]
+                                          step through a day's simulation in the terminal
+    new <day> [--examples N]              create aocN in the year's src with N example files and register it
+    fetch <day>                           download the puzzle input unless it is already cached
+    submit <day> <part> [ANSWER] [--input PATH]
+                                          submit ANSWER, or the computed answer, for a part
+    bench <day|all> [--input PATH] [--warmup N] [--iterations N] [--json] [--alloc]
+          [--history PATH] [--no-record] [--compare] [--baseline COMMIT]
+          [--threshold PCT] [--budgets PATH]
+                                          time parsing and both parts over repeated runs
+    compare <day> [--input PATH] [--warmup N] [--iterations N]
+                                          run every implementation of a day's parts and time them
+
+Every command works on the days of one year, `--year` (default 2024, the latest registered).
+
+PATH may be `-` to read stdin. By default a day reads `<year>/aocN/input` from $AOC_INPUT_DIR,
+falling back to `aocN/input` in the `src` directory of the year's crate, e.g. aoc2024/src.
+
+Output is tab separated: `day part answer` for run, `day part answer status ms` for all,
+`day phase median min p95 samples` for bench with times in nanoseconds. `--json` prints bench
+results as a single JSON document. `--alloc` runs each phase once more counting its heap use and
+adds `allocations bytes peak_bytes` columns (a `memory` object with `--json`).
+
+Every bench run is appended to the history file (default `bench_history.tsv` in the workspace)
+together with the current git commit. `--compare` checks the run against the latest recorded
+run of the same year, or the latest run of `--baseline COMMIT`, and reports phases that got more than
+`--threshold` percent (default 10) slower or exceed a budget from `--budgets PATH`
+(lines of `<day|*> <phase|*> <ms>`). Reports go to stderr and make the exit status 1.
+
+fetch authenticates with the session cookie in $AOC_SESSION and stores the input in
+$AOC_INPUT_DIR/<year>/aocN/input, or the year's src/aocN/input. Requests are at least 5 seconds
+apart.
+
+submit logs every judged answer in src/aocN/submissions of the year's crate and never posts an
+answer the log already rules out. Correct answers are added to src/aocN/answers. The exit status
+is 1 unless the answer is correct.
+
+all checks each day's `input` against src/aocN/answers. A day that panics or fails to parse
+only fails its own rows; the exit status is 1 if any row did.
+
+picture saves `aocN-<name>.png` for each picture a day draws, `--scale` pixels (default 4) per
+cell, in `--dir` or $AOC_IMAGE_DIR, falling back to the workspace's `target/images`. It prints the
+paths of the saved files.
+
+animate saves the same way as picture, as `aocN-<name>.gif` with a frame for every `--every`
+steps (default 1), `--delay` milliseconds (default 100) apart. `--png` saves numbered frames
+`aocN-<name>/00000.png`, ... instead.
+
+compare runs the day's own parts and the alternatives it registers on the same input and
+prints `day part implementation answer agreement median min p95 samples`. The first
+implementation of a part that answers is the reference; the exit status is 1 if another one
+gives a different answer or panics.
+
+debug shows the first simulation of a day, or the one called NAME, one step at a time. It reads
+commands from stdin (`h` lists them): steps forward and back, and runs until a step with given
+tags, e.g. `u column blocked` for a failed push of a box column on day 15.";
+
+#[derive(Debug, Default)]
+struct Options {
+    year: Option<u32>,
+    part: Option<u32>,
+    input: Option<String>,
+    warmup: Option<usize>,
+    iterations: Option<usize>,
+    json: bool,
+    alloc: bool,
+    history: Option<String>,
+    record: bool,
+    compare: bool,
+    baseline: Option<String>,
+    threshold: Option<f64>,
+    budgets: Option<String>,
+    examples: Option<usize>,
+    scale: Option<usize>,
+    dir: Option<String>,
+    every: Option<usize>,
+    delay: Option<usize>,
+    png: bool,
+    sim: Option<String>,
+}
+
+fn fail(msg: &str) -> ! {
+    eprintln!("error: {}\n\n{}", msg, USAGE);
+    process::exit(2);
+}
+
+fn parse_options(args: &[String]) -> Options {
+    let mut options = Options { record: true, ..Options::default() };
+    let mut i = 0;
+    while i < args.len() {
+        // Options without a value
+        match args[i].as_str() {
+            "--json" => options.json = true,
+            "--alloc" => options.alloc = true,
+            "--compare" => options.compare = true,
+            "--png" => options.png = true,
+            "--no-record" => options.record = false,
+            _ => (),
+        }
+        if ["--json", "--alloc", "--compare", "--no-record", "--png"].contains(&args[i].as_str()) {
+            i += 1;
+            continue;
+        }
+        let value = args.get(i + 1).unwrap_or_else(|| fail(&format!("missing value for {}", args[i])));
+        let count = || value.parse::<usize>().unwrap_or_else(|_| fail(&format!("{} expects a number, got {}", args[i], value)));
+        match args[i].as_str() {
+            "--year" => options.year = Some(value.parse::<u32>().unwrap_or_else(|_| fail(&format!("invalid year {}", value)))),
+            "--part" => options.part = match value.as_str() {
+                "1" => Some(1),
+                "2" => Some(2),
+                _ => fail(&format!("part must be 1 or 2, got {}", value)),
+            },
+            "--input" => options.input = Some(value.clone()),
+            "--warmup" => options.warmup = Some(count()),
+            "--iterations" => options.iterations = Some(count()),
+            "--examples" => options.examples = Some(count()),
+            "--scale" => options.scale = Some(count()),
+            "--dir" => options.dir = Some(value.clone()),
+            "--every" => options.every = Some(count()).filter(|&n| n > 0).or_else(|| fail("--every must be at least 1")),
+            "--delay" => options.delay = Some(count()),
+            "--sim" => options.sim = Some(value.clone()),
+            "--history" => options.history = Some(value.clone()),
+            "--baseline" => {
+                options.baseline = Some(value.clone());
+                options.compare = true;
+            },
+            "--threshold" => options.threshold = Some(value.parse::<f64>().unwrap_or_else(|_| fail(&format!("--threshold expects a percentage, got {}", value)))),
+            "--budgets" => {
+                options.budgets = Some(value.clone());
+                options.compare = true;
+            },
+            _ => fail(&format!("unknown option {}", args[i])),
+        }
+        i += 2;
+    }
+    options
+}
+
+fn year(options: &Options) -> &'static Year {
+    match options.year {
+        Some(year) => YEARS.iter().find(|y| y.year == year).unwrap_or_else(|| fail(&format!("year {} has no solutions", year))),
+        None => YEARS[YEARS.len() - 1],
+    }
+}
+
+fn parse_day(s: &str, options: &Options) -> &'static Day {
+    let day = s.parse::<u32>().unwrap_or_else(|_| fail(&format!("invalid day {}", s)));
+    let year = year(options);
+    aoc::find_day(YEARS, year.year, day).unwrap_or_else(|| fail(&format!("day {} of {} is not implemented", day, year.year)))
+}
+
+fn input_source(day: &Day, options: &Options) -> InputSource {
+    match options.input.as_deref() {
+        Some("-") => InputSource::Stdin,
+        Some(p) => InputSource::File(p.into()),
+        None => InputSource::File(day_file(year(options), day.day, "input")),
+    }
+}
+
+fn load(source: &InputSource) -> Vec<String> {
+    source.read().unwrap_or_else(|e| {
+        eprintln!("error: couldn't read input: {}", e);
+        process::exit(1);
+    })
+}
+
+fn check<T>(source: &InputSource, parsed: Result<T, AocError>) -> T {
+    parsed.unwrap_or_else(|e| {
+        eprintln!("error: {}: {}", source, e);
+        process::exit(1);
+    })
+}
+
+fn run(day: &Day, options: &Options) {
+    let source = input_source(day, options);
+    let solution = check(&source, day.parse(load(&source)));
+    if options.part != Some(2) {
+        println!("{}\t1\t{}", day.day, solution.part1());
+    }
+    if options.part != Some(1) {
+        println!("{}\t2\t{}", day.day, solution.part2());
+    }
+}
+
+fn run_all(options: &Options) {
+    // Panics are reported in the table instead
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| ()));
+    let runs = runner::run_all(year(options));
+    panic::set_hook(hook);
+    print!("{}", runner::table(&runs));
+    if runs.iter().flat_map(|r| &r.parts).any(|p| !p.status.is_ok()) {
+        process::exit(1);
+    }
+}
+
+fn export(options: &Options) -> Export {
+    let mut export = Export::from_env();
+    if let Some(dir) = &options.dir {
+        export.dir = dir.into();
+    }
+    export.scale = options.scale.unwrap_or(export.scale as usize) as u32;
+    export
+}
+
+fn picture(day: &Day, options: &Options) {
+    let source = input_source(day, options);
+    let solution = check(&source, day.parse(load(&source)));
+    let export = export(options);
+    let pictures = solution.pictures();
+    if pictures.is_empty() {
+        eprintln!("error: day {} draws no pictures", day.day);
+        process::exit(1);
+    }
+    for (name, canvas) in pictures {
+        match export.save(&canvas, &format!("aoc{}-{}", day.day, name)) {
+            Ok(path) => println!("{}", path.display()),
+            Err(e) => {
+                eprintln!("error: couldn't save picture {}: {}", name, e);
+                process::exit(1);
+            },
+        }
+    }
+}
+
+fn animate(day: &Day, options: &Options) {
+    let source = input_source(day, options);
+    let solution = check(&source, day.parse(load(&source)));
+    let export = export(options);
+    let format = if options.png { FrameFormat::Png } else { FrameFormat::Gif };
+    let delay = Duration::from_millis(options.delay.unwrap_or(100) as u64);
+    let animations = solution.animations(options.every.unwrap_or(1));
+    if animations.is_empty() {
+        eprintln!("error: day {} has no animations", day.day);
+        process::exit(1);
+    }
+    for (name, frames) in animations {
+        match export.save_frames(&frames, &format!("aoc{}-{}", day.day, name), format, delay) {
+            Ok(paths) => paths.iter().for_each(|p| println!("{}", p.display())),
+            Err(e) => {
+                eprintln!("error: couldn't save animation {}: {}", name, e);
+                process::exit(1);
+            },
+        }
+    }
+}
+
+fn debug(day: &Day, options: &Options) {
+    let source = input_source(day, options);
+    let solution = check(&source, day.parse(load(&source)));
+    let mut simulations = solution.simulations();
+    let names: Vec<String> = simulations.iter().map(|(name, _)| name.clone()).collect();
+    let k = match &options.sim {
+        Some(sim) => names.iter().position(|n| n == sim).unwrap_or_else(|| {
+            eprintln!("error: day {} has no simulation {}, only: {}", day.day, sim, names.join(" "));
+            process::exit(1);
+        }),
+        None if names.is_empty() => {
+            eprintln!("error: day {} has no simulations", day.day);
+            process::exit(1);
+        },
+        None => 0,
+    };
+    let mut debugger = Debugger::new(simulations.swap_remove(k).1);
+    let interactive = io::stdin().is_terminal() && io::stdout().is_terminal();
+    if let Err(e) = debugger::run(&mut debugger, io::stdin().lock(), &mut io::stdout(), interactive) {
+        eprintln!("error: {}", e);
+        process::exit(1);
+    }
+}
+
+fn compare(day: &Day, options: &Options) {
+    let source = input_source(day, options);
+    let solution = check(&source, day.parse(load(&source)));
+    // Panics are reported in the table instead
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| ()));
+    let comparisons = compare::compare(solution.as_ref(), &bench_config(options));
+    panic::set_hook(hook);
+    print!("{}", compare::table(day.day, &comparisons));
+    if comparisons.iter().any(|c| !c.agreement.is_ok()) {
+        process::exit(1);
+    }
+}
+
+fn bench_config(options: &Options) -> BenchConfig {
+    let default = BenchConfig::default();
+    BenchConfig {
+        warmup: options.warmup.unwrap_or(default.warmup),
+        iterations: options.iterations.unwrap_or(default.iterations),
+        max_time: default.max_time,
+        memory: options.alloc,
+    }
+}
+
+fn bench(days: &[&Day], options: &Options) {
+    let config = bench_config(options);
+    let year = year(options).year;
+    let mut results = vec![];
+    for day in days {
+        let source = input_source(day, options);
+        let result = check(&source, bench_day(day, &load(&source), &config));
+        if !options.json {
+            for (k, (phase, stats)) in result.phases().iter().enumerate() {
+                let memory = match &result.memory {
+                    Some(m) => format!("\t{}\t{}\t{}", m[k].allocations, m[k].bytes, m[k].peak),
+                    None => String::new(),
+                };
+                println!("{}\t{}\t{}\t{}\t{}\t{}{}", day.day, phase, stats.median.as_nanos(), stats.min.as_nanos(), stats.p95.as_nanos(), stats.samples, memory);
+            }
+        }
+        results.push(result);
+    }
+    if options.json {
+        println!("{}", bench::to_json(&results, &config));
+    }
+
+    let path = options.history.as_ref().map(PathBuf::from).unwrap_or_else(history::default_path);
+    let recorded = history::load(&path).unwrap_or_else(|e| {
+        eprintln!("error: couldn't read benchmark history: {}", e);
+        process::exit(1);
+    });
+    if options.record {
+        let records = history::records(&results, year, &history::git_commit(), history::now());
+        if let Err(e) = history::append(&path, &records) {
+            eprintln!("error: couldn't write benchmark history {}: {}", path.display(), e);
+            process::exit(1);
+        }
+    }
+    if options.compare {
+        let budgets = match &options.budgets {
+            Some(p) => history::read_budgets(Path::new(p)).unwrap_or_else(|e| {
+                eprintln!("error: couldn't read budgets: {}", e);
+                process::exit(1);
+            }),
+            None => vec![],
+        };
+        let baseline = history::baseline(&recorded, options.baseline.as_deref());
+        let flags = history::compare(&results, year, &baseline, options.threshold.unwrap_or(10.0) / 100.0, &budgets);
+        for flag in &flags {
+            eprintln!("{}", flag);
+        }
+        if !flags.is_empty() {
+            process::exit(1);
+        }
+    }
+}
+
+fn submit(day: u32, part: u32, answer: Option<String>, options: &Options) {
+    let year = year(options);
+    let answer = answer.unwrap_or_else(|| {
+        let day = year.get_day(day).unwrap_or_else(|| fail(&format!("day {} is not implemented, give the answer", day)));
+        let source = input_source(day, options);
+        let solution = check(&source, day.parse(load(&source)));
+        let answer = if part == 1 { solution.part1() } else { solution.part2() };
+        answer.submission().unwrap_or_else(|| {
+            eprintln!("error: day {} part {} found no answer to submit", day.day, part);
+            process::exit(1);
+        })
+    });
+    match Client::from_env(year).submit(day, part, &answer) {
+        Ok(submission) => {
+            let logged = if submission.posted { "" } else { " (from the submission log)" };
+            println!("{}\t{}\t{}\t{}{}", day, part, answer, submission.verdict, logged);
+            if submission.verdict != Verdict::Correct {
+                process::exit(1);
+            }
+        },
+        Err(e) => {
+            eprintln!("error: {}", e);
+            process::exit(1);
+        },
+    }
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let command = args.first().unwrap_or_else(|| fail("missing command"));
+    match command.as_str() {
+        "run" | "picture" | "debug" | "animate" | "compare" => {
+            let options = parse_options(args.get(2..).unwrap_or(&[]));
+            let day = parse_day(args.get(1).unwrap_or_else(|| fail("missing day")), &options);
+            match command.as_str() {
+                "run" => run(day, &options),
+                "picture" => picture(day, &options),
+                "debug" => debug(day, &options),
+                "compare" => compare(day, &options),
+                _ => animate(day, &options),
+            }
+        },
+        "all" => run_all(&parse_options(&args[1..])),
+        "list" => {
+            for day in year(&parse_options(&args[1..])).days {
+                println!("{}", day.day);
+            }
+        },
+        "bench" => {
+            let target = args.get(1).unwrap_or_else(|| fail("missing day"));
+            let options = parse_options(&args[2..]);
+            if target == "all" {
+                if options.input.is_some() {
+                    fail("--input cannot be used with bench all");
+                }
+                bench(&year(&options).days.iter().collect::<Vec<&Day>>(), &options);
+            } else {
+                bench(&[parse_day(target, &options)], &options);
+            }
+        },
+        "new" => {
+            let day = args.get(1).unwrap_or_else(|| fail("missing day"));
+            let day = day.parse::<u32>().unwrap_or_else(|_| fail(&format!("invalid day {}", day)));
+            let options = parse_options(&args[2..]);
+            // The year crate, the parent of its `src`
+            let root = Path::new(year(&options).dir).parent().unwrap();
+            match scaffold::create_day(root, day, options.examples.unwrap_or(1)) {
+                Ok(created) => created.iter().for_each(|p| println!("{}", p.display())),
+                Err(e) => {
+                    eprintln!("error: {}", e);
+                    process::exit(1);
+                },
+            }
+        },
+        "fetch" => {
+            let day = args.get(1).unwrap_or_else(|| fail("missing day"));
+            let day = day.parse::<u32>().unwrap_or_else(|_| fail(&format!("invalid day {}", day)));
+            let options = parse_options(&args[2..]);
+            match Client::from_env(year(&options)).input(day) {
+                Ok(path) => println!("{}", path.display()),
+                Err(e) => {
+                    eprintln!("error: {}", e);
+                    process::exit(1);
+                },
+            }
+        },
+        "submit" => {
+            let day = args.get(1).unwrap_or_else(|| fail("missing day"));
+            let day = day.parse::<u32>().unwrap_or_else(|_| fail(&format!("invalid day {}", day)));
+            let part = match args.get(2).map(|p| p.as_str()) {
+                Some("1") => 1,
+                Some("2") => 2,
+                _ => fail("part must be 1 or 2"),
+            };
+            let (answer, rest) = match args.get(3) {
+                Some(a) if !a.starts_with("--") => (Some(a.clone()), &args[4..]),
+                _ => (None, &args[3..]),
+            };
+            submit(day, part, answer, &parse_options(rest));
+        },
+        "-h" | "--help" | "help" => println!("{}", USAGE),
+        _ => fail(&format!("unknown command {}", command)),
+    }
+}
@@ -0,0 +1,23 @@
+use aoc::Answer;
+
+// Keeps every stone in one list, in order. Only part 1: 75 blinks don't fit in memory.
+pub fn solve(inp: Vec<String>) -> [Option<Answer>; 2] {
+    let mut stones: Vec<u64> = inp[0].split_whitespace().map(|s| s.parse().unwrap()).collect();
+    for _ in 0..25 {
+        let mut next = Vec::with_capacity(stones.len() * 2);
+        for stone in stones {
+            let digits = stone.to_string();
+            if stone == 0 {
+                next.push(1);
+            } else if digits.len() % 2 == 0 {
+                let (left, right) = digits.split_at(digits.len() / 2);
+                next.push(left.parse().unwrap());
+                next.push(right.parse().unwrap());
+            } else {
+                next.push(stone * 2024);
+            }
+        }
+        stones = next;
+    }
+    [Some(stones.len().into()), None]
+}
@@ -0,0 +1,18 @@
+use aoc::utils::parse::{integers_n, records};
+use aoc::Answer;
+
+// Tries every combination of at most 100 presses of each button. Only part 1, since part 2
+// needs trillions of presses.
+pub fn solve(inp: Vec<String>) -> [Option<Answer>; 2] {
+    let mut tokens = 0;
+    for record in records(&inp, 3) {
+        let [ax, ay, bx, by, px, py] = integers_n::<i64, 6>(record.start, &record.lines.join(" "), "a machine").unwrap();
+        let cheapest = (0..=100)
+            .flat_map(|a| (0..=100).map(move |b| (a, b)))
+            .filter(|(a, b)| a * ax + b * bx == px && a * ay + b * by == py)
+            .map(|(a, b)| 3 * a + b)
+            .min();
+        tokens += cheapest.unwrap_or(0);
+    }
+    [Some(tokens.into()), None]
+}
@@ -0,0 +1,86 @@
+use aoc::utils::rng::Rng;
+use aoc::Answer;
+
+// The program of the puzzle input, which part 2 is written for
+pub const PROGRAM: [u64; 16] = [2, 4, 1, 5, 7, 5, 1, 6, 0, 3, 4, 2, 5, 5, 3, 0];
+
+// Straightforward interpreter, running until the program halts
+pub fn run(mut a: u64, mut b: u64, mut c: u64, program: &[u64]) -> Vec<u64> {
+    let mut out = vec![];
+    let mut ip = 0;
+    while ip + 1 < program.len() {
+        let literal = program[ip + 1];
+        let combo = || match literal {
+            0..=3 => literal,
+            4 => a,
+            5 => b,
+            6 => c,
+            _ => panic!("reserved combo operand 7"),
+        };
+        match program[ip] {
+            0 => a >>= combo(),
+            1 => b ^= literal,
+            2 => b = combo() % 8,
+            3 if a != 0 => {
+                ip = literal as usize;
+                continue;
+            },
+            3 => (),
+            4 => b ^= c,
+            5 => out.push(combo() % 8),
+            6 => b = a >> combo(),
+            7 => c = a >> combo(),
+            op => panic!("unknown instruction {}", op),
+        }
+        ip += 2;
+    }
+    out
+}
+
+// Smallest A for which `program` outputs `target`, trying every A in turn. The program shifts
+// A by 3 bits per output, so A below 8^len(target) suffices.
+pub fn lowest_a(program: &[u64], b: u64, c: u64, target: &[u64]) -> Option<u64> {
+    (0..8u64.pow(target.len() as u32)).find(|&a| run(a, b, c, program) == target)
+}
+
+// A short output of `PROGRAM`, as a comma separated line
+pub fn generate_target(rng: &mut Rng) -> Vec<String> {
+    let digits = rng.range(1, 5) as u32;
+    let a = rng.range(0, 8i64.pow(digits) - 1) as u64;
+    let mut target = run(a, 0, 0, &PROGRAM);
+    // Sometimes an output that may not be reachable at all
+    if rng.chance(0.3) {
+        let k = rng.below(target.len());
+        target[k] = rng.range(0, 7) as u64;
+    }
+    vec![target.iter().map(|v| v.to_string()).collect::<Vec<String>>().join(",")]
+}
+
+// A program short enough to scan for part 2, like the example `0,3,5,4,3,0`: `adv 3` and an
+// `out` in either order, then a jump back to the start
+pub fn generate_short(rng: &mut Rng) -> Vec<String> {
+    let program = if rng.chance(0.2) {
+        vec![0, 3, 5, 4, 3, 0]
+    } else {
+        let out = [5, rng.range(0, 6) as u64];
+        let body = if rng.chance(0.5) { [[0, 3], out] } else { [out, [0, 3]] };
+        body.concat().into_iter().chain([3, 0]).collect()
+    };
+    vec![
+        format!("Register A: {}", rng.range(0, 100000)),
+        format!("Register B: {}", rng.range(0, 7)),
+        format!("Register C: {}", rng.range(0, 7)),
+        String::new(),
+        format!("Program: {}", program.iter().map(|v| v.to_string()).collect::<Vec<String>>().join(",")),
+    ]
+}
+
+// Part 2 only for short programs, the puzzle's is much too long to scan for
+pub fn solve(inp: Vec<String>) -> [Option<Answer>; 2] {
+    let number = |line: &String| line.rsplit(' ').next().unwrap().to_owned();
+    let registers: Vec<u64> = inp[..3].iter().map(|l| number(l).parse().unwrap()).collect();
+    let program: Vec<u64> = number(&inp[4]).split(',').map(|v| v.parse().unwrap()).collect();
+    let out = run(registers[0], registers[1], registers[2], &program);
+    let part2 = (program.len() <= 6).then(|| lowest_a(&program, registers[1], registers[2], &program).into());
+    [Some(out.iter().map(|v| v.to_string()).collect::<Vec<String>>().join(",").into()), part2]
+}
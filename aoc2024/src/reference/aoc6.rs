@@ -0,0 +1,51 @@
+use aoc::Answer;
+use std::collections::HashSet;
+
+const DIRS: [(i64, i64); 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+
+// Walk from the guard; the cells visited, or `None` if the guard ends up in a loop
+fn walk(grid: &Vec<Vec<char>>, start: (i64, i64)) -> Option<HashSet<(i64, i64)>> {
+    let blocked = |(r, c): (i64, i64)| grid.get(r as usize).and_then(|row| row.get(c as usize)) == Some(&'#');
+    let inside = |(r, c): (i64, i64)| r >= 0 && c >= 0 && (r as usize) < grid.len() && (c as usize) < grid[0].len();
+    let (mut pos, mut dir) = (start, 0);
+    let mut states = HashSet::new();
+    while inside(pos) {
+        if !states.insert((pos, dir)) {
+            return None;
+        }
+        let next = (pos.0 + DIRS[dir].0, pos.1 + DIRS[dir].1);
+        if inside(next) && blocked(next) {
+            dir = (dir + 1) % 4;
+        } else {
+            pos = next;
+        }
+    }
+    Some(states.iter().map(|&(p, _)| p).collect())
+}
+
+// Part 2 tries an obstruction on every free cell and walks the whole route again
+pub fn solve(inp: Vec<String>) -> [Option<Answer>; 2] {
+    let mut grid: Vec<Vec<char>> = inp.iter().map(|l| l.chars().collect()).collect();
+    let mut start = (0, 0);
+    for (r, row) in grid.iter().enumerate() {
+        for (c, &ch) in row.iter().enumerate() {
+            if ch == '^' {
+                start = (r as i64, c as i64);
+            }
+        }
+    }
+    let visited = walk(&grid, start).map(|v| v.len());
+    let mut loops = 0;
+    for r in 0..grid.len() {
+        for c in 0..grid[r].len() {
+            if grid[r][c] == '.' {
+                grid[r][c] = '#';
+                if walk(&grid, start).is_none() {
+                    loops += 1;
+                }
+                grid[r][c] = '.';
+            }
+        }
+    }
+    [visited.map(Answer::from), Some(loops.into())]
+}
@@ -0,0 +1,65 @@
+use aoc::utils::prop;
+use aoc::utils::rng::Rng;
+use crate::YEAR;
+use aoc::{Answer, Solution};
+
+// Deliberately naive solutions, used as an oracle for the optimised ones. They follow the puzzle
+// text literally and are only fast enough for small generated inputs.
+
+pub mod aoc6;
+pub mod aoc11;
+pub mod aoc13;
+pub mod aoc17;
+
+pub struct Reference {
+    pub day: u32,
+    // Inputs small enough for `solve`
+    pub generate: fn(&mut Rng) -> Vec<String>,
+    // Answers to both parts, `None` for a part without a reference
+    pub solve: fn(Vec<String>) -> [Option<Answer>; 2],
+}
+
+pub const REFERENCES: &[Reference] = &[
+    Reference { day: 6, generate: crate::aoc6::generate, solve: aoc6::solve },
+    Reference { day: 11, generate: crate::aoc11::generate, solve: aoc11::solve },
+    Reference { day: 13, generate: crate::aoc13::generate, solve: aoc13::solve },
+    Reference { day: 17, generate: crate::aoc17::generate, solve: aoc17::solve },
+    Reference { day: 17, generate: aoc17::generate_short, solve: aoc17::solve },
+];
+
+// Compare the registered solver of `reference.day` with the reference on `cases` generated
+// inputs. Fails like `prop::check`, with the seed and input of the first mismatch.
+pub fn differential(reference: &Reference, cases: u64) {
+    let day = YEAR.get_day(reference.day).unwrap_or_else(|| panic!("day {} is not registered", reference.day));
+    prop::check(cases, reference.generate, |inp| {
+        let solution = day.parse(inp.clone()).unwrap();
+        for (k, expected) in (reference.solve)(inp).iter().enumerate() {
+            if let Some(expected) = expected {
+                let answer = if k == 0 { solution.part1() } else { solution.part2() };
+                assert_eq!(&answer, expected, "day {} part {}", day.day, k + 1);
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod reference {
+    use super::*;
+
+    #[test]
+    fn differential() {
+        for reference in REFERENCES {
+            super::differential(reference, 30);
+        }
+    }
+
+    #[test]
+    fn aoc17_lowest_a() {
+        // Part 2 looks for the program itself, too long for a linear scan, so look for short outputs
+        prop::check(30, aoc17::generate_target, |inp| {
+            let target: Vec<u64> = inp[0].split(',').map(|v| v.parse().unwrap()).collect();
+            let context = crate::aoc17::Context::new(vec![0, 0, 0], aoc17::PROGRAM.to_vec());
+            assert_eq!(context.lowest_a(&target), aoc17::lowest_a(&aoc17::PROGRAM, 0, 0, &target));
+        });
+    }
+}
@@ -1,24 +0,0 @@
-#![allow(warnings)]
-#[allow(dead_code)]
-#[allow(unused_imports)]
-mod utils;
-mod aoc1;
-mod aoc2;
-mod aoc3;
-mod aoc4;
-mod aoc5;
-mod aoc6;
-mod aoc7;
-mod aoc8;
-mod aoc9;
-mod aoc10;
-mod aoc11;
-mod aoc12;
-mod aoc13;
-mod aoc14;
-mod aoc15;
-mod aoc16;
-
-mod aoc17;
-
-mod aoc18;